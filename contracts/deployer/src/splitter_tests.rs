@@ -46,6 +46,9 @@ fn test_deploy_from_address() {
             depth: 100,
             len: 1000
         }).unwrap(),
+        (),
+        0_i128,
+        (),
     ).into_val(&env);
     env.mock_all_auths();
     let (contract_id, init_result) =
@@ -107,7 +110,7 @@ fn test_deploy_from_address() {
     .unwrap()
     .into_val(&env);
 
-    let shares = client.list_shares();
+    let shares = client.list_shares(&None);
     assert_eq!(shares.len(), 2);
     assert_eq!(shares.get(0).unwrap().shareholder, shareholder1);
     assert_eq!(shares.get(0).unwrap().share, 2000);
@@ -1,10 +1,17 @@
-use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 use crate::{
     errors::Error,
     logic::execute,
     logic::query,
-    storage::{ConfigDataKey, ShareDataKey},
+    storage::{
+        ConfigDataKey, ContributionDataKey, CorrectionDataKey, DepositDataKey, Distribution,
+        FeeDataKey, FixedAmountDataKey, FreezeDataKey, KeeperBountyDataKey, MerkleRootDataKey,
+        OperatorDataKey, PayoutCap, ReclaimMode, RecoupmentDataKey, ScheduledSharesDataKey,
+        ShareDataKey, ShareVersionDataKey, TierDataKey,
+    },
 };
 
 contractmeta!(
@@ -23,11 +30,17 @@ pub trait SplitterTrait {
     /// * `admin` - The admin address for the contract
     /// * `shares` - The shareholders with their shares
     /// * `mutable` - Whether the contract is mutable or not
+    /// * `fee_recipient` - The recipient of the distribution fee
+    /// * `fee` - The fee carved out of every distribution in basis points
+    /// * `arbiter` - The address allowed to freeze allocations during disputes, if any
     fn init(
         env: Env,
         admin: Address,
         shares: Vec<ShareDataKey>,
         mutable: bool,
+        fee_recipient: Option<Address>,
+        fee: i128,
+        arbiter: Option<Address>,
     ) -> Result<(), Error>;
 
     // ========== Execute Functions ==========
@@ -53,12 +66,70 @@ pub trait SplitterTrait {
 
     /// Distributes tokens to the shareholders.
     ///
-    /// All of the available token balance is distributed on execution.
+    /// All of the unused token balance is distributed on execution.
+    /// The fee is carved out of the distributed amount before the shareholder split.
+    /// Tiers are filled in order up to their caps before the rest is split between the shareholders.
+    /// If a stream duration is configured, the distributed amounts are released
+    /// linearly to the shareholders over the configured number of ledgers.
+    ///
+    /// The distribution is executed by the admin if no keeper is given.
+    /// Otherwise anyone can distribute as a keeper if a keeper bounty is configured,
+    /// in which case the keeper is paid the bounty out of the distributed amount.
+    /// Keepers always split with the default share table and cannot pass a tag.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute
+    /// * `keeper` - The address of the keeper executing the distribution
+    /// * `tag` - The tag of the share table to split with, or None for the default share table
+    fn distribute_tokens(
+        env: Env,
+        token_address: Address,
+        keeper: Option<Address>,
+        tag: Option<Symbol>,
+    ) -> Result<(), Error>;
+
+    /// Deposits tokens and distributes them to the shareholders.
+    ///
+    /// The amount is pulled from the payer and distributed on execution,
+    /// leaving the unused token balance untouched.
+    /// The deposit is recorded in the contributions of the payer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The address of the payer
+    /// * `token_address` - The address of the token to deposit
+    /// * `amount` - The amount of tokens to deposit
+    /// * `memo` - An optional reference for the deposit
+    /// * `tag` - The tag of the share table to split with, or None for the default share table
+    fn deposit(
+        env: Env,
+        from: Address,
+        token_address: Address,
+        amount: i128,
+        memo: Option<String>,
+        tag: Option<Symbol>,
+    ) -> Result<(), Error>;
+
+    /// Receives tokens from a parent splitter and distributes them to the shareholders.
+    ///
+    /// The amount is pulled from the sender and distributed on execution,
+    /// leaving the unused token balance untouched.
+    /// Distributions are cascaded further to child splitters until the maximum depth is reached.
     ///
     /// ## Arguments
     ///
+    /// * `from` - The address sending the tokens
     /// * `token_address` - The address of the token to distribute
-    fn distribute_tokens(env: Env, token_address: Address) -> Result<(), Error>;
+    /// * `amount` - The amount of tokens to distribute
+    /// * `depth` - The number of splitters the distribution has cascaded through
+    fn cascade_tokens(
+        env: Env,
+        from: Address,
+        token_address: Address,
+        amount: i128,
+        depth: u32,
+    ) -> Result<(), Error>;
 
     /// **ADMIN ONLY FUNCTION**
     ///
@@ -73,128 +144,1626 @@ pub trait SplitterTrait {
 
     /// **ADMIN ONLY FUNCTION**
     ///
-    /// Locks the contract for further shares updates.
+    /// Schedules a share table that replaces the default share table at the activation ledger.
     ///
-    /// Locking the contract does not affect the distribution of tokens.
-    fn lock_contract(env: Env) -> Result<(), Error>;
+    /// Distributions use the scheduled share table from the activation ledger onwards.
+    /// A share table scheduled at the same ledger is replaced.
+    ///
+    /// ## Arguments
+    ///
+    /// * `activation_ledger` - The ledger sequence at which the share table becomes active
+    /// * `shares` - The shareholders with their shares
+    fn schedule_shares(
+        env: Env,
+        activation_ledger: u32,
+        shares: Vec<ShareDataKey>,
+    ) -> Result<(), Error>;
 
-    /// Withdraws the allocation of the shareholder for the token.
+    /// **ADMIN ONLY FUNCTION**
     ///
-    /// A shareholder can withdraw their allocation for a token if they have any.
+    /// Cancels a share table that is scheduled but not active yet.
     ///
     /// ## Arguments
     ///
-    /// * `token_address` - The address of the token to withdraw
-    /// * `shareholder` - The address of the shareholder
-    /// * `amount` - The amount of tokens to withdraw
-    fn withdraw_allocation(
+    /// * `activation_ledger` - The activation ledger of the scheduled share table
+    fn cancel_scheduled_shares(env: Env, activation_ledger: u32) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the share table overriding the default share table for a token.
+    ///
+    /// Distributions of the token without a tag are split with the override.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    /// * `shares` - The shareholders with their shares, or None to remove the override
+    fn update_token_shares(
         env: Env,
         token_address: Address,
-        shareholder: Address,
-        amount: i128,
+        shares: Option<Vec<ShareDataKey>>,
     ) -> Result<(), Error>;
 
-    // ========== Query Functions ==========
-
-    /// Gets the share of a shareholder.
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the share table of a tag.
+    ///
+    /// Deposits and distributions with the tag are split with the share table of the tag
+    /// instead of the default share table.
     ///
     /// ## Arguments
     ///
-    /// * `shareholder` - The address of the shareholder
+    /// * `tag` - The tag of the share table
+    /// * `shares` - The shareholders with their shares
+    fn update_tagged_shares(env: Env, tag: Symbol, shares: Vec<ShareDataKey>) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
     ///
-    /// ## Returns
+    /// Removes the share table of a tag.
     ///
-    /// * `Option<i128>` - The share of the shareholder if it exists
-    fn get_share(env: Env, shareholder: Address) -> Result<Option<i128>, Error>;
+    /// ## Arguments
+    ///
+    /// * `tag` - The tag of the share table
+    fn remove_tagged_shares(env: Env, tag: Symbol) -> Result<(), Error>;
 
-    /// Lists all of the shareholders with their shares.
+    /// **ADMIN ONLY FUNCTION**
     ///
-    /// ## Returns
+    /// Updates the fixed amounts paid for a token.
     ///
-    /// * `Vec<ShareDataKey>` - The list of shareholders with their shares
-    fn list_shares(env: Env) -> Result<Vec<ShareDataKey>, Error>;
+    /// Fixed amounts are allocated to their recipients before the tiers and the shares.
+    /// A fixed amount with a period is only paid once every period ledgers.
+    /// Fixed amounts are paid in list order and the first one the balance cannot cover
+    /// stops the fixed payments of that distribution. Unpaid periodic amounts stay due.
+    /// Recipients must be unique.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    /// * `fixed_amounts` - The list of recipients with their fixed amounts and periods
+    fn update_fixed_amounts(
+        env: Env,
+        token_address: Address,
+        fixed_amounts: Vec<FixedAmountDataKey>,
+    ) -> Result<(), Error>;
 
-    /// Gets the contract configuration.
+    /// **ADMIN ONLY FUNCTION**
     ///
-    /// ## Returns
+    /// Updates the ordered list of tiers.
     ///
-    /// * `ConfigDataKey` - The contract configuration
-    fn get_config(env: Env) -> Result<ConfigDataKey, Error>;
+    /// Each tier receives the distributions for a token until its cumulative cap for the token
+    /// is reached, after which the next tier is filled. Tokens without a cap skip the tier.
+    /// Tiers with an unchanged share table keep their fill progress, the others start empty.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tiers` - The ordered list of tiers with their shares and caps
+    fn update_tiers(env: Env, tiers: Vec<TierDataKey>) -> Result<(), Error>;
 
-    /// Gets the allocation of a shareholder for a token.
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the payout cap of a shareholder for a token.
+    ///
+    /// A capped shareholder cannot receive more than the cap over the lifetime of the contract.
+    /// The excess is spread over the remaining shareholders in proportion to their shares.
     ///
     /// ## Arguments
     ///
     /// * `shareholder` - The address of the shareholder
-    /// * `token` - The address of the token
+    /// * `token_address` - The address of the token
+    /// * `cap` - The payout cap, or None to remove the cap
+    fn update_payout_cap(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        cap: Option<i128>,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
     ///
-    /// ## Returns
+    /// Enables or disables cascading the distributions to a shareholder.
     ///
-    /// * `i128` - The allocation of the shareholder for the token
-    fn get_allocation(env: Env, shareholder: Address, token: Address) -> Result<i128, Error>;
-}
+    /// If the shareholder is a splitter contract, its allocations are forwarded to it
+    /// and its distribution is triggered in the same call. Otherwise, or if the
+    /// cascade fails, the allocation is credited to the shareholder as usual.
+    /// Streamed distributions are never cascaded.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `enabled` - Whether the distributions are cascaded to the shareholder
+    fn update_cascade(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error>;
 
-#[contract]
-pub struct Splitter;
+    /// Enables or disables the allocation callback for a shareholder.
+    ///
+    /// Shareholders that opt in are notified through an `on_split_allocated(token, amount)`
+    /// call whenever they are credited in a distribution. Failing callbacks are ignored,
+    /// the allocation is still credited and the distribution is not reverted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `enabled` - Whether the shareholder is notified of its allocations
+    fn update_callback(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error>;
 
-#[contractimpl]
-impl SplitterTrait for Splitter {
-    // ========== Execute Functions ==========
+    /// Sets the address receiving the payouts of a shareholder.
+    ///
+    /// The shareholder keeps its place in the share tables while withdrawals and
+    /// cascaded distributions are sent to the payout address.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `payout_address` - The address receiving the payouts, `None` to receive them directly
+    fn update_payout_address(
+        env: Env,
+        shareholder: Address,
+        payout_address: Option<Address>,
+    ) -> Result<(), Error>;
 
-    fn init(
+    /// Sets the tokens settled before the share of a shareholder changes.
+    ///
+    /// Transfers settle the tokens of both sides and renouncing settles the tokens of the
+    /// shareholder, distributing their unused balances with the current shares first.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `tokens` - The tokens to settle, at most 10, an empty list to settle none
+    fn update_settlement_tokens(
         env: Env,
-        admin: Address,
-        shares: Vec<ShareDataKey>,
-        mutable: bool,
-    ) -> Result<(), Error> {
-        execute::init(env, admin, shares, mutable)
-    }
+        shareholder: Address,
+        tokens: Vec<Address>,
+    ) -> Result<(), Error>;
 
-    fn transfer_tokens(
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Records an advance paid to a shareholder.
+    ///
+    /// The shareholder's allocations of the token are diverted to the recoupment recipient
+    /// until the advance is repaid, after which normal payouts resume.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    /// * `amount` - The amount of the advance
+    /// * `recipient` - The address receiving the diverted allocations
+    fn record_advance(
         env: Env,
+        shareholder: Address,
         token_address: Address,
-        recipient: Address,
         amount: i128,
-    ) -> Result<(), Error> {
-        execute::transfer_tokens(env, token_address, recipient, amount)
-    }
-
-    fn distribute_tokens(env: Env, token_address: Address) -> Result<(), Error> {
-        execute::distribute_tokens(env, token_address)
-    }
-
-    fn update_shares(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
-        execute::update_shares(env, shares)
-    }
-
-    fn lock_contract(env: Env) -> Result<(), Error> {
-        execute::lock_contract(env)
-    }
+        recipient: Address,
+    ) -> Result<(), Error>;
 
-    fn withdraw_allocation(
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Sets a shareholder's allocation of a token to a corrected value.
+    ///
+    /// Only available while the contract is not locked. Increases are taken from the unused
+    /// balance, decreases release the tokens back to it. Streams are trimmed so they never lock
+    /// more than the corrected allocation. Every correction is recorded in the correction log
+    /// together with its reason.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    /// * `allocation` - The corrected allocation
+    /// * `reason` - The reason for the correction
+    fn adjust_allocation(
         env: Env,
+        shareholder: Address,
         token_address: Address,
+        allocation: i128,
+        reason: String,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Claws back part of a shareholder's allocation of a token and transfers it to a recipient.
+    ///
+    /// Only available while the contract is not locked. The clawback is recorded in the
+    /// correction log together with its reason.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    /// * `amount` - The amount to claw back
+    /// * `recipient` - The address receiving the clawed back tokens
+    /// * `reason` - The reason for the clawback
+    fn clawback_allocation(
+        env: Env,
         shareholder: Address,
+        token_address: Address,
         amount: i128,
-    ) -> Result<(), Error> {
-        execute::withdraw_allocation(env, token_address, shareholder, amount)
-    }
+        recipient: Address,
+        reason: String,
+    ) -> Result<(), Error>;
 
-    // ========== Query Functions ==========
+    /// **ARBITER ONLY FUNCTION**
+    ///
+    /// Freezes the withdrawals of a shareholder's allocations while a dispute is resolved.
+    ///
+    /// Distributions keep accruing to the shareholder during the freeze. A freeze lasts at most
+    /// `MAX_FREEZE_DURATION` ledgers from its start, including any extensions. Freezing again
+    /// keeps the start of the dispute until the last freeze has been over for
+    /// `MAX_FREEZE_DURATION` ledgers.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `duration` - The number of ledgers the freeze lasts, at most `MAX_FREEZE_DURATION`
+    fn freeze_allocation(env: Env, shareholder: Address, duration: u32) -> Result<(), Error>;
 
-    fn get_share(env: Env, shareholder: Address) -> Result<Option<i128>, Error> {
-        query::get_share(env, shareholder)
-    }
+    /// **ARBITER ONLY FUNCTION**
+    ///
+    /// Lifts the freeze of a shareholder's allocations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    fn unfreeze_allocation(env: Env, shareholder: Address) -> Result<(), Error>;
 
-    fn list_shares(env: Env) -> Result<Vec<ShareDataKey>, Error> {
-        query::list_shares(env)
-    }
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Locks the contract for further shares updates.
+    ///
+    /// Locking the contract does not affect the distribution of tokens.
+    /// Share tables scheduled for a later ledger are cancelled.
+    fn lock_contract(env: Env) -> Result<(), Error>;
 
-    fn get_config(env: Env) -> Result<ConfigDataKey, Error> {
-        query::get_config(env)
-    }
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates whether the shareholders can transfer their shares on a locked contract.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shares_transferable` - Whether the shares can be transferred once the contract is locked
+    fn update_shares_transferable(env: Env, shares_transferable: bool) -> Result<(), Error>;
 
-    fn get_allocation(env: Env, shareholder: Address, token: Address) -> Result<i128, Error> {
-        query::get_allocation(env, shareholder, token)
+    /// Transfers all or part of the share of a shareholder to another address.
+    ///
+    /// The shares keep summing up to 10000 and the change is versioned like any other
+    /// share table update. Existing allocations stay with the sender.
+    /// The tagged share tables, the token overrides, the scheduled share tables and the tiers
+    /// naming the sender move the same part of the sender's share, rounded down.
+    /// The unused balances of the settlement tokens of both sides are distributed
+    /// with the current shares before the transfer.
+    /// Locked contracts only allow transfers if the shares are transferable.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The address of the shareholder transferring the share
+    /// * `to` - The address receiving the share
+    /// * `amount_bps` - The share to transfer in basis points
+    fn transfer_share(env: Env, from: Address, to: Address, amount_bps: i128) -> Result<(), Error>;
+
+    /// Removes the shareholder from the share table at their own request.
+    ///
+    /// The unused balances of the settlement tokens of the shareholder are distributed with
+    /// the current shares first and existing allocations stay with the shareholder until they are withdrawn.
+    /// The renounced share is split between the remaining shareholders pro rata,
+    /// the basis points lost to rounding going to the largest remainders so the total stays 10000.
+    /// The share is renounced the same way in the tagged share tables, the token overrides,
+    /// the scheduled share tables and the tiers naming the shareholder.
+    /// Locked contracts only allow renouncing if the shares are transferable.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder leaving the splitter
+    fn renounce_share(env: Env, shareholder: Address) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Exposes the default share table as a SEP-41 token.
+    ///
+    /// Balances are the shares in basis points, shown as percentages with 2 decimals.
+    /// Once enabled, the share token can be transferred even if the contract is locked
+    /// and it cannot be disabled.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - The name of the share token
+    /// * `symbol` - The symbol of the share token
+    fn enable_share_token(env: Env, name: String, symbol: String) -> Result<(), Error>;
+
+    /// Transfers share token balance from one shareholder to another.
+    ///
+    /// The unused balances of the settlement tokens of both sides are distributed with the current
+    /// shares before the transfer, so the revenue earned by the sender does not move with the share.
+    /// Locked contracts only allow transfers if the shares are transferable.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The address of the shareholder transferring the share
+    /// * `to` - The address receiving the share
+    /// * `amount` - The share to transfer in basis points
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), Error>;
+
+    /// Transfers share token balance on behalf of a shareholder using the allowance of the spender.
+    ///
+    /// Pending distributions are settled and locks are enforced the same way as in `transfer`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `spender` - The address spending the allowance
+    /// * `from` - The address of the shareholder transferring the share
+    /// * `to` - The address receiving the share
+    /// * `amount` - The share to transfer in basis points
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error>;
+
+    /// Allows the spender to transfer share token balance on behalf of the shareholder.
+    ///
+    /// An amount of 0 revokes the allowance.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The address of the shareholder
+    /// * `spender` - The address allowed to transfer the share
+    /// * `amount` - The share the spender can transfer in basis points
+    /// * `expiration_ledger` - The ledger sequence after which the allowance expires
+    fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the number of ledgers the distributions are streamed over.
+    ///
+    /// Setting the duration to 0 allocates the distributions at once.
+    ///
+    /// ## Arguments
+    ///
+    /// * `stream_duration` - The number of ledgers a distribution is streamed over
+    fn update_stream_duration(env: Env, stream_duration: u32) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the number of ledgers an allocation can stay unclaimed before it can be reclaimed.
+    ///
+    /// The window runs from the last withdrawal or from the oldest credit not withdrawn yet.
+    /// Setting the window to 0 keeps the allocations forever.
+    ///
+    /// ## Arguments
+    ///
+    /// * `claim_window` - The number of ledgers an allocation can stay unclaimed
+    /// * `reclaim_mode` - Whether reclaimed allocations return to the unused balance
+    ///   or are split between the other shareholders
+    fn update_claim_window(
+        env: Env,
+        claim_window: u32,
+        reclaim_mode: ReclaimMode,
+    ) -> Result<(), Error>;
+
+    /// Reclaims an allocation whose claim window has passed.
+    ///
+    /// Anyone can trigger the reclamation. Depending on the reclaim mode, the allocation
+    /// returns to the unused balance or is distributed again like any other distribution,
+    /// the part falling to the reclaimed shareholder staying in the unused balance.
+    /// Amounts still locked in streams are not reclaimed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    fn reclaim_allocation(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the bounty paid to the keepers calling the distribution.
+    ///
+    /// Keeper distributions are disabled if the bounty is removed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `keeper_bounty` - The fee, the fee cap and the minimum distribution interval for keepers
+    fn update_keeper_bounty(
+        env: Env,
+        keeper_bounty: Option<KeeperBountyDataKey>,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the fee carved out of every distribution.
+    ///
+    /// The fee cannot be updated after the contract is locked.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fee_recipient` - The recipient of the distribution fee
+    /// * `fee` - The fee carved out of every distribution in basis points
+    fn update_fee(env: Env, fee_recipient: Option<Address>, fee: i128) -> Result<(), Error>;
+
+    /// Withdraws the allocation of the shareholder for the token.
+    ///
+    /// A shareholder can withdraw their allocation for a token if they have any.
+    /// Only the part of the allocation that is streamed so far can be withdrawn.
+    /// Operators approved by the shareholder can withdraw on their behalf within their limits,
+    /// the tokens are always sent to the payout address of the shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to withdraw
+    /// * `shareholder` - The address of the shareholder
+    /// * `amount` - The amount of tokens to withdraw
+    /// * `operator` - The operator withdrawing on behalf of the shareholder, if any
+    fn withdraw_allocation(
+        env: Env,
+        token_address: Address,
+        shareholder: Address,
+        amount: i128,
+        operator: Option<Address>,
+    ) -> Result<(), Error>;
+
+    /// Approves an operator to withdraw the allocations on behalf of a shareholder.
+    ///
+    /// Replaces any existing approval of the operator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `operator` - The address of the operator
+    /// * `expiration_ledger` - The ledger sequence after which the approval expires
+    /// * `limits` - The amount the operator can withdraw per token, other tokens are unlimited
+    fn approve_operator(
+        env: Env,
+        shareholder: Address,
+        operator: Address,
+        expiration_ledger: u32,
+        limits: Map<Address, i128>,
+    ) -> Result<(), Error>;
+
+    /// Revokes the approval of an operator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `operator` - The address of the operator
+    fn revoke_operator(env: Env, shareholder: Address, operator: Address) -> Result<(), Error>;
+
+    /// Withdraws the allocation of a shareholder with a claim signed by the shareholder.
+    ///
+    /// Lets a relayer submit the claim for shareholders that hold no XLM for fees.
+    /// The shareholder signs the payload returned by `get_claim_payload` with their ed25519 key,
+    /// the nonce is consumed so the claim cannot be replayed.
+    /// The relayer fee is deducted from the claimed amount and the rest is sent
+    /// to the payout address of the shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder_pubkey` - The ed25519 public key of the shareholder account
+    /// * `token_address` - The address of the token to withdraw
+    /// * `amount` - The amount of tokens to withdraw, including the relayer fee
+    /// * `nonce` - The current claim nonce of the shareholder
+    /// * `expiry` - The last ledger sequence the claim can be submitted at
+    /// * `max_relayer_fee` - The maximum fee the shareholder agreed to pay to the relayer
+    /// * `signature` - The ed25519 signature of the claim payload
+    /// * `relayer` - The address receiving the relayer fee, if any
+    /// * `relayer_fee` - The fee paid to the relayer
+    #[allow(clippy::too_many_arguments)]
+    fn claim_with_signature(
+        env: Env,
+        shareholder_pubkey: BytesN<32>,
+        token_address: Address,
+        amount: i128,
+        nonce: u64,
+        expiry: u32,
+        max_relayer_fee: i128,
+        signature: BytesN<64>,
+        relayer: Option<Address>,
+        relayer_fee: i128,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Posts the merkle root of a distribution as the next epoch of the token.
+    ///
+    /// Merkle distributions pay large sets of recipients without storing them on chain.
+    /// The total is reserved from the unused balance until it is claimed or recovered.
+    /// Leaves are `sha256(sha256(index || recipient || amount))` with the big endian index and amount
+    /// and the XDR of the recipient address. Pairs of nodes are hashed in ascending order.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the distributed token
+    /// * `root` - The root of the merkle tree
+    /// * `total` - The sum of the amounts of the leaves
+    /// * `deadline` - The last ledger sequence the recipients can claim at
+    fn post_merkle_root(
+        env: Env,
+        token_address: Address,
+        root: BytesN<32>,
+        total: i128,
+        deadline: u32,
+    ) -> Result<(), Error>;
+
+    /// Claims the amount of a leaf of a merkle distribution.
+    ///
+    /// Anyone can submit the claim, the tokens are always sent to the payout address
+    /// of the recipient.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the distributed token
+    /// * `epoch` - The epoch of the merkle root
+    /// * `index` - The index of the leaf
+    /// * `recipient` - The recipient of the leaf
+    /// * `amount` - The amount of the leaf
+    /// * `proof` - The sibling nodes from the leaf up to the root
+    fn claim_merkle(
+        env: Env,
+        token_address: Address,
+        epoch: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Releases the unclaimed amount of a merkle distribution back to the unused balance
+    /// once the deadline has passed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the distributed token
+    /// * `epoch` - The epoch of the merkle root
+    fn recover_merkle_funds(env: Env, token_address: Address, epoch: u32) -> Result<(), Error>;
+
+    // ========== Query Functions ==========
+
+    /// Gets the share of a shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token whose share table is used, or None for the default share table
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<i128>` - The share of the shareholder if it exists
+    fn get_share(
+        env: Env,
+        shareholder: Address,
+        token: Option<Address>,
+    ) -> Result<Option<i128>, Error>;
+
+    /// Lists all of the shareholders with their shares.
+    ///
+    /// The default share table is used for a token without an override.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token whose share table is used, or None for the default share table
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<ShareDataKey>` - The list of shareholders with their shares
+    fn list_shares(env: Env, token: Option<Address>) -> Result<Vec<ShareDataKey>, Error>;
+
+    /// Gets a past or current version of a share table.
+    ///
+    /// The default, tagged and token share tables are versioned with the same sequence of ids.
+    /// Removed share tables are kept as an empty version.
+    ///
+    /// ## Arguments
+    ///
+    /// * `id` - The id of the version
+    ///
+    /// ## Returns
+    ///
+    /// * `ShareVersionDataKey` - The share table with its source and the ledger sequence it took effect
+    fn get_shares_at_version(env: Env, id: u32) -> Result<ShareVersionDataKey, Error>;
+
+    /// Gets the id of the latest share table version.
+    ///
+    /// Versions are promoted lazily, a scheduled share table is versioned
+    /// by the first execution after its activation ledger, before it is used for a distribution.
+    ///
+    /// ## Returns
+    ///
+    /// * `u32` - The id of the latest version
+    fn current_share_version(env: Env) -> Result<u32, Error>;
+
+    /// Returns the share token balance of an address
+    ///
+    /// ## Arguments
+    ///
+    /// * `id` - The address to get the balance of
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The share of the address in basis points
+    fn balance(env: Env, id: Address) -> Result<i128, Error>;
+
+    /// Returns the share token balance the spender can transfer on behalf of the shareholder
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The address of the shareholder
+    /// * `spender` - The address of the spender
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The allowance in basis points, 0 if it has expired
+    fn allowance(env: Env, from: Address, spender: Address) -> Result<i128, Error>;
+
+    /// Returns the number of decimals of the share token
+    fn decimals(env: Env) -> Result<u32, Error>;
+
+    /// Returns the name of the share token
+    fn name(env: Env) -> Result<String, Error>;
+
+    /// Returns the symbol of the share token
+    fn symbol(env: Env) -> Result<String, Error>;
+
+    /// Lists the upcoming share tables in the order of their activation.
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<ScheduledSharesDataKey>` - The share tables with their activation ledgers
+    fn list_scheduled_shares(env: Env) -> Result<Vec<ScheduledSharesDataKey>, Error>;
+
+    /// Lists the tags that have a share table.
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<Symbol>` - The list of tags
+    fn list_tags(env: Env) -> Result<Vec<Symbol>, Error>;
+
+    /// Lists the shareholders of a tag with their shares.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tag` - The tag of the share table
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<ShareDataKey>` - The list of shareholders with their shares
+    fn list_tagged_shares(env: Env, tag: Symbol) -> Result<Vec<ShareDataKey>, Error>;
+
+    /// Lists the fixed amounts paid for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<FixedAmountDataKey>` - The list of recipients with their fixed amounts and periods
+    fn list_fixed_amounts(env: Env, token: Address) -> Result<Vec<FixedAmountDataKey>, Error>;
+
+    /// Lists all of the tiers in order.
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<TierDataKey>` - The list of tiers with their shares and caps
+    fn list_tiers(env: Env) -> Result<Vec<TierDataKey>, Error>;
+
+    /// Gets the amount of a token that is distributed to a tier.
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the tier
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The amount of the token that is distributed to the tier
+    fn get_tier_filled(env: Env, index: u32, token: Address) -> Result<i128, Error>;
+
+    /// Gets the payout cap of a shareholder for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<PayoutCap>` - The cap and the received amount if the shareholder is capped
+    fn get_payout_cap(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<PayoutCap>, Error>;
+
+    /// Gets the outstanding advance of a shareholder for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<RecoupmentDataKey>` - The recipient and the outstanding balance if there is any
+    fn get_recoupment(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<RecoupmentDataKey>, Error>;
+
+    /// Returns whether the distributions are cascaded to a shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the distributions are cascaded to the shareholder
+    fn get_cascade(env: Env, shareholder: Address) -> Result<bool, Error>;
+
+    /// Returns whether a shareholder is notified of its allocations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the allocation callback is enabled for the shareholder
+    fn get_callback(env: Env, shareholder: Address) -> Result<bool, Error>;
+
+    /// Returns the active freeze of a shareholder's allocations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<FreezeDataKey>` - The freeze, None if the allocations are not frozen
+    fn get_freeze(env: Env, shareholder: Address) -> Result<Option<FreezeDataKey>, Error>;
+
+    /// Returns the address receiving the payouts of a shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `Address` - The payout address, the shareholder itself if none is set
+    fn get_payout_address(env: Env, shareholder: Address) -> Result<Address, Error>;
+
+    /// Returns the tokens settled before the share of a shareholder changes.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<Address>` - The settlement tokens of the shareholder
+    fn get_settlement_tokens(env: Env, shareholder: Address) -> Result<Vec<Address>, Error>;
+
+    /// Returns the approval of an operator withdrawing on behalf of a shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `operator` - The address of the operator
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<OperatorDataKey>` - The approval with the remaining limits, `None` if it has expired
+    fn get_operator(
+        env: Env,
+        shareholder: Address,
+        operator: Address,
+    ) -> Result<Option<OperatorDataKey>, Error>;
+
+    /// Returns the nonce the next signed claim of a shareholder must use.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `u64` - The nonce of the next signed claim
+    fn get_claim_nonce(env: Env, shareholder: Address) -> Result<u64, Error>;
+
+    /// Returns the payload a shareholder signs for `claim_with_signature`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token to withdraw
+    /// * `amount` - The amount of tokens to withdraw, including the relayer fee
+    /// * `nonce` - The current claim nonce of the shareholder
+    /// * `expiry` - The last ledger sequence the claim can be submitted at
+    /// * `max_relayer_fee` - The maximum fee the shareholder agrees to pay to the relayer
+    ///
+    /// ## Returns
+    ///
+    /// * `Bytes` - The payload to sign
+    fn get_claim_payload(
+        env: Env,
+        token: Address,
+        amount: i128,
+        nonce: u64,
+        expiry: u32,
+        max_relayer_fee: i128,
+    ) -> Result<Bytes, Error>;
+
+    /// Returns the number of merkle roots posted for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `u32` - The number of epochs, the next root is posted as this epoch
+    fn get_merkle_epochs(env: Env, token: Address) -> Result<u32, Error>;
+
+    /// Returns the merkle root of a token for an epoch.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token
+    /// * `epoch` - The epoch of the merkle root
+    ///
+    /// ## Returns
+    ///
+    /// * `MerkleRootDataKey` - The root with its reserved and claimed amounts
+    fn get_merkle_root(env: Env, token: Address, epoch: u32) -> Result<MerkleRootDataKey, Error>;
+
+    /// Returns whether a leaf of a merkle distribution is claimed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The address of the token
+    /// * `epoch` - The epoch of the merkle root
+    /// * `index` - The index of the leaf
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the leaf is claimed
+    fn is_merkle_claimed(env: Env, token: Address, epoch: u32, index: u32) -> Result<bool, Error>;
+
+    /// Gets the lifetime contribution of a payer for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `payer` - The address of the payer
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `ContributionDataKey` - The total amount deposited and the number of deposits
+    fn get_contributions(
+        env: Env,
+        payer: Address,
+        token: Address,
+    ) -> Result<ContributionDataKey, Error>;
+
+    /// Gets the memo given with a deposit of a token made by a payer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `payer` - The address of the payer
+    /// * `token` - The address of the token
+    /// * `index` - The index of the deposit
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<String>` - The memo of the deposit, `None` if it was made without one
+    fn get_deposit_memo(
+        env: Env,
+        payer: Address,
+        token: Address,
+        index: u32,
+    ) -> Result<Option<String>, Error>;
+
+    /// Lists the deposits of a token made by a payer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `payer` - The address of the payer
+    /// * `token` - The address of the token
+    /// * `start` - The index of the first deposit
+    /// * `limit` - The maximum number of deposits to return
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<DepositDataKey>` - The deposits with their amounts and ledgers
+    fn list_deposits(
+        env: Env,
+        payer: Address,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<DepositDataKey>, Error>;
+
+    /// Lists the payers in the order of their first deposit.
+    ///
+    /// ## Arguments
+    ///
+    /// * `start` - The index of the first payer
+    /// * `limit` - The maximum number of payers to return
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<Address>` - The addresses of the payers
+    fn list_payers(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error>;
+
+    /// Lists the allocation corrections in the order they were made.
+    ///
+    /// ## Arguments
+    ///
+    /// * `start` - The index of the first correction
+    /// * `limit` - The maximum number of corrections to return
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<CorrectionDataKey>` - The logged corrections
+    fn list_corrections(env: Env, start: u32, limit: u32) -> Result<Vec<CorrectionDataKey>, Error>;
+
+    /// Identifies the contract as a splitter for the parent splitters.
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Always true
+    fn is_splitter(env: Env) -> bool;
+
+    /// Gets the contract configuration.
+    ///
+    /// ## Returns
+    ///
+    /// * `ConfigDataKey` - The contract configuration
+    fn get_config(env: Env) -> Result<ConfigDataKey, Error>;
+
+    /// Gets the ledger sequence from which an allocation can be reclaimed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<u32>` - The first ledger the allocation can be reclaimed at,
+    ///   `None` if there is no claim window or allocation
+    fn get_reclaim_ledger(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<u32>, Error>;
+
+    /// Gets the arbiter that can freeze the allocations of a shareholder during disputes.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Address>` - The arbiter if one was configured at init
+    fn get_arbiter(env: Env) -> Result<Option<Address>, Error>;
+
+    /// Gets the fee carved out of every distribution.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<FeeDataKey>` - The fee recipient and the fee if a fee is configured
+    fn get_fee(env: Env) -> Result<Option<FeeDataKey>, Error>;
+
+    /// Gets the bounty paid to the keepers calling the distribution.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<KeeperBountyDataKey>` - The keeper bounty if keeper distributions are enabled
+    fn get_keeper_bounty(env: Env) -> Result<Option<KeeperBountyDataKey>, Error>;
+
+    /// Gets the allocation of a shareholder for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The allocation of the shareholder for the token
+    fn get_allocation(env: Env, shareholder: Address, token: Address) -> Result<i128, Error>;
+
+    /// Gets the released allocation of a shareholder for a token.
+    ///
+    /// The released allocation is the part of the allocation that can be withdrawn.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The released allocation of the shareholder for the token
+    fn get_released_allocation(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<i128, Error>;
+
+    /// Previews the distribution of a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute
+    /// * `keeper` - The address of the keeper executing the distribution
+    /// * `tag` - The tag of the share table to split with, or None for the default share table
+    ///
+    /// ## Returns
+    ///
+    /// * `Distribution` - The fee, the keeper bounty and the allocations of the distribution
+    fn preview_distribution(
+        env: Env,
+        token_address: Address,
+        keeper: Option<Address>,
+        tag: Option<Symbol>,
+    ) -> Result<Distribution, Error>;
+}
+
+#[contract]
+pub struct Splitter;
+
+#[contractimpl]
+impl SplitterTrait for Splitter {
+    // ========== Execute Functions ==========
+
+    fn init(
+        env: Env,
+        admin: Address,
+        shares: Vec<ShareDataKey>,
+        mutable: bool,
+        fee_recipient: Option<Address>,
+        fee: i128,
+        arbiter: Option<Address>,
+    ) -> Result<(), Error> {
+        execute::init(env, admin, shares, mutable, fee_recipient, fee, arbiter)
+    }
+
+    fn transfer_tokens(
+        env: Env,
+        token_address: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        execute::transfer_tokens(env, token_address, recipient, amount)
+    }
+
+    fn distribute_tokens(
+        env: Env,
+        token_address: Address,
+        keeper: Option<Address>,
+        tag: Option<Symbol>,
+    ) -> Result<(), Error> {
+        execute::distribute_tokens(env, token_address, keeper, tag)
+    }
+
+    fn deposit(
+        env: Env,
+        from: Address,
+        token_address: Address,
+        amount: i128,
+        memo: Option<String>,
+        tag: Option<Symbol>,
+    ) -> Result<(), Error> {
+        execute::deposit(env, from, token_address, amount, memo, tag)
+    }
+
+    fn cascade_tokens(
+        env: Env,
+        from: Address,
+        token_address: Address,
+        amount: i128,
+        depth: u32,
+    ) -> Result<(), Error> {
+        execute::cascade_tokens(env, from, token_address, amount, depth)
+    }
+
+    fn update_shares(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
+        execute::update_shares(env, shares)
+    }
+
+    fn schedule_shares(
+        env: Env,
+        activation_ledger: u32,
+        shares: Vec<ShareDataKey>,
+    ) -> Result<(), Error> {
+        execute::schedule_shares(env, activation_ledger, shares)
+    }
+
+    fn cancel_scheduled_shares(env: Env, activation_ledger: u32) -> Result<(), Error> {
+        execute::cancel_scheduled_shares(env, activation_ledger)
+    }
+
+    fn update_token_shares(
+        env: Env,
+        token_address: Address,
+        shares: Option<Vec<ShareDataKey>>,
+    ) -> Result<(), Error> {
+        execute::update_token_shares(env, token_address, shares)
+    }
+
+    fn update_tagged_shares(env: Env, tag: Symbol, shares: Vec<ShareDataKey>) -> Result<(), Error> {
+        execute::update_tagged_shares(env, tag, shares)
+    }
+
+    fn remove_tagged_shares(env: Env, tag: Symbol) -> Result<(), Error> {
+        execute::remove_tagged_shares(env, tag)
+    }
+
+    fn update_fixed_amounts(
+        env: Env,
+        token_address: Address,
+        fixed_amounts: Vec<FixedAmountDataKey>,
+    ) -> Result<(), Error> {
+        execute::update_fixed_amounts(env, token_address, fixed_amounts)
+    }
+
+    fn update_tiers(env: Env, tiers: Vec<TierDataKey>) -> Result<(), Error> {
+        execute::update_tiers(env, tiers)
+    }
+
+    fn update_payout_cap(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        cap: Option<i128>,
+    ) -> Result<(), Error> {
+        execute::update_payout_cap(env, shareholder, token_address, cap)
+    }
+
+    fn update_cascade(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error> {
+        execute::update_cascade(env, shareholder, enabled)
+    }
+
+    fn update_callback(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error> {
+        execute::update_callback(env, shareholder, enabled)
+    }
+
+    fn update_payout_address(
+        env: Env,
+        shareholder: Address,
+        payout_address: Option<Address>,
+    ) -> Result<(), Error> {
+        execute::update_payout_address(env, shareholder, payout_address)
+    }
+
+    fn update_settlement_tokens(
+        env: Env,
+        shareholder: Address,
+        tokens: Vec<Address>,
+    ) -> Result<(), Error> {
+        execute::update_settlement_tokens(env, shareholder, tokens)
+    }
+
+    fn record_advance(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        amount: i128,
+        recipient: Address,
+    ) -> Result<(), Error> {
+        execute::record_advance(env, shareholder, token_address, amount, recipient)
+    }
+
+    fn adjust_allocation(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        allocation: i128,
+        reason: String,
+    ) -> Result<(), Error> {
+        execute::adjust_allocation(env, shareholder, token_address, allocation, reason)
+    }
+
+    fn clawback_allocation(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        amount: i128,
+        recipient: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        execute::clawback_allocation(env, shareholder, token_address, amount, recipient, reason)
+    }
+
+    fn freeze_allocation(env: Env, shareholder: Address, duration: u32) -> Result<(), Error> {
+        execute::freeze_allocation(env, shareholder, duration)
+    }
+
+    fn unfreeze_allocation(env: Env, shareholder: Address) -> Result<(), Error> {
+        execute::unfreeze_allocation(env, shareholder)
+    }
+
+    fn lock_contract(env: Env) -> Result<(), Error> {
+        execute::lock_contract(env)
+    }
+
+    fn update_shares_transferable(env: Env, shares_transferable: bool) -> Result<(), Error> {
+        execute::update_shares_transferable(env, shares_transferable)
+    }
+
+    fn transfer_share(env: Env, from: Address, to: Address, amount_bps: i128) -> Result<(), Error> {
+        execute::transfer_share(env, from, to, amount_bps)
+    }
+
+    fn renounce_share(env: Env, shareholder: Address) -> Result<(), Error> {
+        execute::renounce_share(env, shareholder)
+    }
+
+    fn enable_share_token(env: Env, name: String, symbol: String) -> Result<(), Error> {
+        execute::enable_share_token(env, name, symbol)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), Error> {
+        execute::transfer(env, from, to, amount)
+    }
+
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        execute::transfer_from(env, spender, from, to, amount)
+    }
+
+    fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        execute::approve(env, from, spender, amount, expiration_ledger)
+    }
+
+    fn update_stream_duration(env: Env, stream_duration: u32) -> Result<(), Error> {
+        execute::update_stream_duration(env, stream_duration)
+    }
+
+    fn update_claim_window(
+        env: Env,
+        claim_window: u32,
+        reclaim_mode: ReclaimMode,
+    ) -> Result<(), Error> {
+        execute::update_claim_window(env, claim_window, reclaim_mode)
+    }
+
+    fn reclaim_allocation(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+    ) -> Result<(), Error> {
+        execute::reclaim_allocation(env, shareholder, token_address)
+    }
+
+    fn update_keeper_bounty(
+        env: Env,
+        keeper_bounty: Option<KeeperBountyDataKey>,
+    ) -> Result<(), Error> {
+        execute::update_keeper_bounty(env, keeper_bounty)
+    }
+
+    fn update_fee(env: Env, fee_recipient: Option<Address>, fee: i128) -> Result<(), Error> {
+        execute::update_fee(env, fee_recipient, fee)
+    }
+
+    fn withdraw_allocation(
+        env: Env,
+        token_address: Address,
+        shareholder: Address,
+        amount: i128,
+        operator: Option<Address>,
+    ) -> Result<(), Error> {
+        execute::withdraw_allocation(env, token_address, shareholder, amount, operator)
+    }
+
+    fn approve_operator(
+        env: Env,
+        shareholder: Address,
+        operator: Address,
+        expiration_ledger: u32,
+        limits: Map<Address, i128>,
+    ) -> Result<(), Error> {
+        execute::approve_operator(env, shareholder, operator, expiration_ledger, limits)
+    }
+
+    fn revoke_operator(env: Env, shareholder: Address, operator: Address) -> Result<(), Error> {
+        execute::revoke_operator(env, shareholder, operator)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn claim_with_signature(
+        env: Env,
+        shareholder_pubkey: BytesN<32>,
+        token_address: Address,
+        amount: i128,
+        nonce: u64,
+        expiry: u32,
+        max_relayer_fee: i128,
+        signature: BytesN<64>,
+        relayer: Option<Address>,
+        relayer_fee: i128,
+    ) -> Result<(), Error> {
+        execute::claim_with_signature(
+            env,
+            shareholder_pubkey,
+            token_address,
+            amount,
+            nonce,
+            expiry,
+            max_relayer_fee,
+            signature,
+            relayer,
+            relayer_fee,
+        )
+    }
+
+    fn post_merkle_root(
+        env: Env,
+        token_address: Address,
+        root: BytesN<32>,
+        total: i128,
+        deadline: u32,
+    ) -> Result<(), Error> {
+        execute::post_merkle_root(env, token_address, root, total, deadline)
+    }
+
+    fn claim_merkle(
+        env: Env,
+        token_address: Address,
+        epoch: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        execute::claim_merkle(env, token_address, epoch, index, recipient, amount, proof)
+    }
+
+    fn recover_merkle_funds(env: Env, token_address: Address, epoch: u32) -> Result<(), Error> {
+        execute::recover_merkle_funds(env, token_address, epoch)
+    }
+
+    // ========== Query Functions ==========
+
+    fn get_share(
+        env: Env,
+        shareholder: Address,
+        token: Option<Address>,
+    ) -> Result<Option<i128>, Error> {
+        query::get_share(env, shareholder, token)
+    }
+
+    fn list_shares(env: Env, token: Option<Address>) -> Result<Vec<ShareDataKey>, Error> {
+        query::list_shares(env, token)
+    }
+
+    fn get_shares_at_version(env: Env, id: u32) -> Result<ShareVersionDataKey, Error> {
+        query::get_shares_at_version(env, id)
+    }
+
+    fn current_share_version(env: Env) -> Result<u32, Error> {
+        query::current_share_version(env)
+    }
+
+    fn balance(env: Env, id: Address) -> Result<i128, Error> {
+        query::balance(env, id)
+    }
+
+    fn allowance(env: Env, from: Address, spender: Address) -> Result<i128, Error> {
+        query::allowance(env, from, spender)
+    }
+
+    fn decimals(env: Env) -> Result<u32, Error> {
+        query::decimals(env)
+    }
+
+    fn name(env: Env) -> Result<String, Error> {
+        query::name(env)
+    }
+
+    fn symbol(env: Env) -> Result<String, Error> {
+        query::symbol(env)
+    }
+
+    fn list_scheduled_shares(env: Env) -> Result<Vec<ScheduledSharesDataKey>, Error> {
+        query::list_scheduled_shares(env)
+    }
+
+    fn list_tags(env: Env) -> Result<Vec<Symbol>, Error> {
+        query::list_tags(env)
+    }
+
+    fn list_tagged_shares(env: Env, tag: Symbol) -> Result<Vec<ShareDataKey>, Error> {
+        query::list_tagged_shares(env, tag)
+    }
+
+    fn list_fixed_amounts(env: Env, token: Address) -> Result<Vec<FixedAmountDataKey>, Error> {
+        query::list_fixed_amounts(env, token)
+    }
+
+    fn list_tiers(env: Env) -> Result<Vec<TierDataKey>, Error> {
+        query::list_tiers(env)
+    }
+
+    fn get_tier_filled(env: Env, index: u32, token: Address) -> Result<i128, Error> {
+        query::get_tier_filled(env, index, token)
+    }
+
+    fn get_payout_cap(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<PayoutCap>, Error> {
+        query::get_payout_cap(env, shareholder, token)
+    }
+
+    fn get_recoupment(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<RecoupmentDataKey>, Error> {
+        query::get_recoupment(env, shareholder, token)
+    }
+
+    fn get_cascade(env: Env, shareholder: Address) -> Result<bool, Error> {
+        query::get_cascade(env, shareholder)
+    }
+
+    fn get_callback(env: Env, shareholder: Address) -> Result<bool, Error> {
+        query::get_callback(env, shareholder)
+    }
+
+    fn get_freeze(env: Env, shareholder: Address) -> Result<Option<FreezeDataKey>, Error> {
+        query::get_freeze(env, shareholder)
+    }
+
+    fn get_payout_address(env: Env, shareholder: Address) -> Result<Address, Error> {
+        query::get_payout_address(env, shareholder)
+    }
+
+    fn get_settlement_tokens(env: Env, shareholder: Address) -> Result<Vec<Address>, Error> {
+        query::get_settlement_tokens(env, shareholder)
+    }
+
+    fn get_operator(
+        env: Env,
+        shareholder: Address,
+        operator: Address,
+    ) -> Result<Option<OperatorDataKey>, Error> {
+        query::get_operator(env, shareholder, operator)
+    }
+
+    fn get_claim_nonce(env: Env, shareholder: Address) -> Result<u64, Error> {
+        query::get_claim_nonce(env, shareholder)
+    }
+
+    fn get_claim_payload(
+        env: Env,
+        token: Address,
+        amount: i128,
+        nonce: u64,
+        expiry: u32,
+        max_relayer_fee: i128,
+    ) -> Result<Bytes, Error> {
+        query::get_claim_payload(env, token, amount, nonce, expiry, max_relayer_fee)
+    }
+
+    fn get_merkle_epochs(env: Env, token: Address) -> Result<u32, Error> {
+        query::get_merkle_epochs(env, token)
+    }
+
+    fn get_merkle_root(env: Env, token: Address, epoch: u32) -> Result<MerkleRootDataKey, Error> {
+        query::get_merkle_root(env, token, epoch)
+    }
+
+    fn is_merkle_claimed(env: Env, token: Address, epoch: u32, index: u32) -> Result<bool, Error> {
+        query::is_merkle_claimed(env, token, epoch, index)
+    }
+
+    fn get_contributions(
+        env: Env,
+        payer: Address,
+        token: Address,
+    ) -> Result<ContributionDataKey, Error> {
+        query::get_contributions(env, payer, token)
+    }
+
+    fn get_deposit_memo(
+        env: Env,
+        payer: Address,
+        token: Address,
+        index: u32,
+    ) -> Result<Option<String>, Error> {
+        query::get_deposit_memo(env, payer, token, index)
+    }
+
+    fn list_deposits(
+        env: Env,
+        payer: Address,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<DepositDataKey>, Error> {
+        query::list_deposits(env, payer, token, start, limit)
+    }
+
+    fn list_payers(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        query::list_payers(env, start, limit)
+    }
+
+    fn list_corrections(env: Env, start: u32, limit: u32) -> Result<Vec<CorrectionDataKey>, Error> {
+        query::list_corrections(env, start, limit)
+    }
+
+    fn is_splitter(env: Env) -> bool {
+        query::is_splitter(env)
+    }
+
+    fn get_config(env: Env) -> Result<ConfigDataKey, Error> {
+        query::get_config(env)
+    }
+
+    fn get_reclaim_ledger(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<Option<u32>, Error> {
+        query::get_reclaim_ledger(env, shareholder, token)
+    }
+
+    fn get_arbiter(env: Env) -> Result<Option<Address>, Error> {
+        query::get_arbiter(env)
+    }
+
+    fn get_fee(env: Env) -> Result<Option<FeeDataKey>, Error> {
+        query::get_fee(env)
+    }
+
+    fn get_keeper_bounty(env: Env) -> Result<Option<KeeperBountyDataKey>, Error> {
+        query::get_keeper_bounty(env)
+    }
+
+    fn get_allocation(env: Env, shareholder: Address, token: Address) -> Result<i128, Error> {
+        query::get_allocation(env, shareholder, token)
+    }
+
+    fn get_released_allocation(
+        env: Env,
+        shareholder: Address,
+        token: Address,
+    ) -> Result<i128, Error> {
+        query::get_released_allocation(env, shareholder, token)
+    }
+
+    fn preview_distribution(
+        env: Env,
+        token_address: Address,
+        keeper: Option<Address>,
+        tag: Option<Symbol>,
+    ) -> Result<Distribution, Error> {
+        query::preview_distribution(env, token_address, keeper, tag)
     }
 }
@@ -17,4 +17,66 @@ pub enum Error {
     // Token withdrawal errors
     ZeroWithdrawalAmount = 10,
     WithdrawalAmountAboveAllocation = 11,
+    WithdrawalAmountAboveReleasedAllocation = 12,
+    // Keeper errors
+    InvalidKeeperBounty = 13,
+    KeeperDistributionDisabled = 14,
+    DistributionIntervalNotReached = 15,
+    // Fee errors
+    InvalidFee = 16,
+    // Tier errors
+    InvalidTierCap = 17,
+    // Payout cap errors
+    InvalidPayoutCap = 18,
+    // Advance errors
+    InvalidAdvanceAmount = 19,
+    RecoupmentRecipientMismatch = 20,
+    // Fixed amount errors
+    InvalidFixedAmount = 21,
+    // Cascade errors
+    InvalidCascadeAmount = 23,
+    CascadeDepthExceeded = 24,
+    // Deposit errors
+    InvalidDepositAmount = 25,
+    // Tag errors
+    TagNotFound = 26,
+    // Schedule errors
+    InvalidActivationLedger = 27,
+    ScheduleNotFound = 28,
+    // Share version errors
+    ShareVersionNotFound = 29,
+    // Share transfer errors
+    InvalidShareTransferAmount = 30,
+    InsufficientShare = 31,
+    // Share token errors
+    ShareTokenNotEnabled = 32,
+    ShareTokenAlreadyEnabled = 33,
+    InvalidAllowanceAmount = 34,
+    InsufficientAllowance = 36,
+    // Share renounce errors
+    ShareholderNotFound = 37,
+    // Operator errors
+    InvalidOperatorLimit = 39,
+    OperatorNotApproved = 40,
+    WithdrawalAmountAboveOperatorLimit = 41,
+    // Signed claim errors
+    InvalidClaimNonce = 43,
+    InvalidRelayerFee = 44,
+    // Merkle distribution errors
+    MerkleRootNotFound = 45,
+    InvalidMerkleProof = 46,
+    MerkleAlreadyClaimed = 47,
+    // Deadline errors
+    DeadlineNotReached = 48,
+    DeadlinePassed = 49,
+    // Freeze errors
+    AllocationFrozen = 50,
+    // Merkle distribution errors
+    MerkleAlreadyRecovered = 51,
+    // Correction errors
+    InvalidAllocation = 52,
+    // Freeze errors
+    InvalidFreezeDuration = 53,
+    // Settlement errors
+    TooManySettlementTokens = 54,
 }
@@ -0,0 +1,64 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::storage::{CorrectionDataKey, Distribution, FreezeDataKey, MerkleRootDataKey};
+
+/// Publishes the distribution of a token
+pub fn distribution(env: &Env, distribution: &Distribution) {
+    let topics = (symbol_short!("distrib"), distribution.token.clone());
+    env.events().publish(topics, distribution.clone());
+}
+
+/// Publishes the amount of a token cascaded to a child splitter
+pub fn cascade(env: &Env, token: &Address, child: &Address, amount: i128) {
+    let topics = (symbol_short!("cascade"), token.clone());
+    env.events().publish(topics, (child.clone(), amount));
+}
+
+/// Publishes the deposit of a token by a payer
+pub fn deposit(env: &Env, token: &Address, from: &Address, amount: i128, memo: &Option<String>) {
+    let topics = (symbol_short!("deposit"), token.clone());
+    env.events()
+        .publish(topics, (from.clone(), amount, memo.clone()));
+}
+
+/// Publishes the transfer of a share token balance
+pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+    let topics = (symbol_short!("transfer"), from.clone(), to.clone());
+    env.events().publish(topics, amount);
+}
+
+/// Publishes the approval of a share token allowance
+pub fn approve(env: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+    let topics = (symbol_short!("approve"), from.clone(), spender.clone());
+    env.events().publish(topics, (amount, expiration_ledger));
+}
+
+/// Publishes the merkle root posted for an epoch of a token
+pub fn merkle_root(env: &Env, token: &Address, epoch: u32, merkle_root: &MerkleRootDataKey) {
+    let topics = (symbol_short!("merkle"), token.clone());
+    env.events().publish(topics, (epoch, merkle_root.clone()));
+}
+
+/// Publishes the reclamation of an allocation that was not claimed in time
+pub fn reclaim(env: &Env, token: &Address, shareholder: &Address, amount: i128) {
+    let topics = (symbol_short!("reclaim"), token.clone());
+    env.events().publish(topics, (shareholder.clone(), amount));
+}
+
+/// Publishes a correction of an allocation by the admin
+pub fn correction(env: &Env, index: u32, correction: &CorrectionDataKey) {
+    let topics = (symbol_short!("correct"), correction.token.clone());
+    env.events().publish(topics, (index, correction.clone()));
+}
+
+/// Publishes the freeze of a shareholder's allocations
+pub fn freeze(env: &Env, shareholder: &Address, freeze: &FreezeDataKey) {
+    let topics = (symbol_short!("freeze"), shareholder.clone());
+    env.events().publish(topics, freeze.clone());
+}
+
+/// Publishes the lifting of a shareholder's freeze
+pub fn unfreeze(env: &Env, shareholder: &Address) {
+    let topics = (symbol_short!("unfreeze"), shareholder.clone());
+    env.events().publish(topics, ());
+}
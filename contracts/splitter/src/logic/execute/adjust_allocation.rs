@@ -0,0 +1,50 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{correct_allocation, get_unused_balance},
+    storage::{AllocationDataKey, ConfigDataKey, CorrectionKind},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+    allocation: i128,
+    reason: String,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Allocation cannot be reduced below 0
+    if allocation < 0 {
+        return Err(Error::InvalidAllocation);
+    };
+
+    // Increases are taken from the unused balance
+    let previous =
+        AllocationDataKey::get_allocation(&env, &shareholder, &token_address).unwrap_or(0);
+    if allocation - previous > get_unused_balance(&env, &token_address) {
+        return Err(Error::TransferAmountAboveUnusedBalance);
+    };
+
+    correct_allocation(
+        &env,
+        CorrectionKind::Adjustment,
+        &shareholder,
+        &token_address,
+        allocation,
+        reason,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    storage::{AllowanceDataKey, ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    from.require_auth();
+
+    // Make sure the shares are exposed as a token
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    // Allowance amount cannot be less than 0
+    if amount < 0 {
+        return Err(Error::InvalidAllowanceAmount);
+    };
+
+    // Allowances cannot expire in the past unless they are revoked
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        return Err(Error::DeadlinePassed);
+    };
+
+    AllowanceDataKey::save_allowance(
+        &env,
+        &from,
+        &spender,
+        &AllowanceDataKey {
+            amount,
+            expiration_ledger,
+        },
+    );
+
+    events::approve(&env, &from, &spender, amount, expiration_ledger);
+
+    Ok(())
+}
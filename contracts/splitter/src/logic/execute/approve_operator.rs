@@ -0,0 +1,45 @@
+use soroban_sdk::{Address, Env, Map};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, OperatorDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    operator: Address,
+    expiration_ledger: u32,
+    limits: Map<Address, i128>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    // Approvals cannot expire in the past
+    if expiration_ledger < env.ledger().sequence() {
+        return Err(Error::DeadlinePassed);
+    };
+
+    // Token limits cannot be equal and less than 0
+    for (_, limit) in limits.iter() {
+        if limit <= 0 {
+            return Err(Error::InvalidOperatorLimit);
+        };
+    }
+
+    OperatorDataKey::save_operator(
+        &env,
+        &shareholder,
+        &operator,
+        &OperatorDataKey {
+            expiration_ledger,
+            limits,
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ScheduledSharesDataKey},
+};
+
+pub fn execute(env: Env, activation_ledger: u32) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Share tables that are already active cannot be cancelled
+    if activation_ledger <= env.ledger().sequence() {
+        return Err(Error::ScheduleNotFound);
+    };
+
+    let mut schedule = ScheduledSharesDataKey::get_schedule(&env);
+    let index = schedule
+        .iter()
+        .position(|scheduled| scheduled.activation_ledger == activation_ledger)
+        .ok_or(Error::ScheduleNotFound)?;
+    schedule.remove(index as u32);
+
+    ScheduledSharesDataKey::save_schedule(&env, &schedule);
+
+    Ok(())
+}
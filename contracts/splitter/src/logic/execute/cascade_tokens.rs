@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::distribute_received_tokens,
+    storage::{ConfigDataKey, MAX_CASCADE_DEPTH},
+};
+
+pub fn execute(
+    env: Env,
+    from: Address,
+    token_address: Address,
+    amount: i128,
+    depth: u32,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the sender of the tokens
+    from.require_auth();
+
+    // Stop cascading once the depth limit is reached to prevent cycles
+    if depth > MAX_CASCADE_DEPTH {
+        return Err(Error::CascadeDepthExceeded);
+    };
+
+    // Cascaded amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::InvalidCascadeAmount);
+    };
+
+    distribute_received_tokens(&env, &from, &token_address, amount, &None, depth)
+}
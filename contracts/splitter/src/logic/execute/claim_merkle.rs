@@ -0,0 +1,62 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{get_merkle_leaf, get_token_client, reserve_amount, verify_merkle_proof},
+    storage::{ConfigDataKey, MerkleRootDataKey, PayoutAddressDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    epoch: u32,
+    index: u32,
+    recipient: Address,
+    amount: i128,
+    proof: Vec<BytesN<32>>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let mut merkle_root = match MerkleRootDataKey::get_root(&env, &token_address, epoch) {
+        Some(merkle_root) => merkle_root,
+        None => return Err(Error::MerkleRootNotFound),
+    };
+
+    // Claims are closed after the deadline
+    if env.ledger().sequence() > merkle_root.deadline {
+        return Err(Error::DeadlinePassed);
+    };
+
+    // Make sure the leaf is not claimed twice
+    if MerkleRootDataKey::is_claimed(&env, &token_address, epoch, index) {
+        return Err(Error::MerkleAlreadyClaimed);
+    };
+
+    // Make sure the leaf is part of the tree and fits in the reserved amount
+    let leaf = get_merkle_leaf(&env, index, &recipient, amount);
+    if !verify_merkle_proof(&env, &merkle_root.root, &leaf, &proof)
+        || amount <= 0
+        || merkle_root.claimed + amount > merkle_root.total
+    {
+        return Err(Error::InvalidMerkleProof);
+    };
+
+    MerkleRootDataKey::set_claimed(&env, &token_address, epoch, index);
+    merkle_root.claimed += amount;
+    MerkleRootDataKey::save_root(&env, &token_address, epoch, &merkle_root);
+
+    // Release the claimed amount from the reservation
+    reserve_amount(&env, &token_address, -amount);
+
+    // Transfer the tokens to the payout address of the recipient
+    let payout_address = PayoutAddressDataKey::get_payout_address(&env, &recipient);
+    get_token_client(&env, &token_address).transfer(
+        &env.current_contract_address(),
+        &payout_address,
+        &amount,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{debit_allocation, get_account_address, get_claim_payload, get_token_client},
+    storage::{ClaimNonceDataKey, ConfigDataKey, PayoutAddressDataKey},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+    env: Env,
+    shareholder_pubkey: BytesN<32>,
+    token_address: Address,
+    amount: i128,
+    nonce: u64,
+    expiry: u32,
+    max_relayer_fee: i128,
+    signature: BytesN<64>,
+    relayer: Option<Address>,
+    relayer_fee: i128,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let shareholder = get_account_address(&env, &shareholder_pubkey);
+
+    // Make sure the claim is still valid
+    if env.ledger().sequence() > expiry {
+        return Err(Error::DeadlinePassed);
+    };
+
+    // Make sure the claim is not replayed
+    if nonce != ClaimNonceDataKey::get_nonce(&env, &shareholder) {
+        return Err(Error::InvalidClaimNonce);
+    };
+
+    // Relayer fee cannot be less than 0 or greater than the signed maximum and the amount
+    let relayer_fee = match relayer {
+        Some(ref relayer) => {
+            relayer.require_auth();
+            relayer_fee
+        }
+        None => 0,
+    };
+    if relayer_fee < 0 || relayer_fee > max_relayer_fee || relayer_fee > amount {
+        return Err(Error::InvalidRelayerFee);
+    };
+
+    // Make sure the claim is signed by the shareholder, panics otherwise
+    let payload = get_claim_payload(&env, &token_address, amount, nonce, expiry, max_relayer_fee);
+    env.crypto()
+        .ed25519_verify(&shareholder_pubkey, &payload, &signature);
+
+    ClaimNonceDataKey::increment_nonce(&env, &shareholder);
+
+    debit_allocation(&env, &shareholder, &token_address, amount)?;
+
+    let token_client = get_token_client(&env, &token_address);
+
+    // Pay the relayer out of the claimed amount
+    if let Some(relayer) = relayer {
+        if relayer_fee > 0 {
+            token_client.transfer(&env.current_contract_address(), &relayer, &relayer_fee);
+        };
+    };
+
+    // Transfer the rest to the payout address of the shareholder
+    let payout_address = PayoutAddressDataKey::get_payout_address(&env, &shareholder);
+    if amount > relayer_fee {
+        token_client.transfer(
+            &env.current_contract_address(),
+            &payout_address,
+            &(amount - relayer_fee),
+        );
+    };
+
+    Ok(())
+}
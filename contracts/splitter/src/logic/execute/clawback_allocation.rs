@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{correct_allocation, get_token_client},
+    storage::{AllocationDataKey, ConfigDataKey, CorrectionKind},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+    amount: i128,
+    recipient: Address,
+    reason: String,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    let allocation =
+        AllocationDataKey::get_allocation(&env, &shareholder, &token_address).unwrap_or(0);
+
+    // Clawback amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::ZeroWithdrawalAmount);
+    };
+    // Clawback amount cannot be greater than the allocation
+    if amount > allocation {
+        return Err(Error::WithdrawalAmountAboveAllocation);
+    };
+
+    correct_allocation(
+        &env,
+        CorrectionKind::Clawback(recipient.clone()),
+        &shareholder,
+        &token_address,
+        allocation - amount,
+        reason,
+    );
+
+    // Transfer the clawed back tokens to the recipient
+    get_token_client(&env, &token_address).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &amount,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::{
+    errors::Error,
+    events,
+    logic::helpers::distribute_received_tokens,
+    storage::{ConfigDataKey, ContributionDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    from: Address,
+    token_address: Address,
+    amount: i128,
+    memo: Option<String>,
+    tag: Option<Symbol>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the payer
+    from.require_auth();
+
+    // Deposit amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::InvalidDepositAmount);
+    };
+
+    distribute_received_tokens(&env, &from, &token_address, amount, &tag, 0)?;
+
+    // Keep track of the contributions of the payer
+    ContributionDataKey::add_deposit(&env, &from, &token_address, amount, &memo);
+
+    events::deposit(&env, &token_address, &from, amount, &memo);
+
+    Ok(())
+}
@@ -1,50 +1,74 @@
-use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Symbol};
 
 use crate::{
     errors::Error,
-    logic::helpers::get_token_client,
-    storage::{AllocationDataKey, ConfigDataKey, ShareDataKey},
+    logic::helpers::{
+        calculate_distribution, execute_distribution, get_unused_balance, promote_scheduled_shares,
+    },
+    storage::{ConfigDataKey, DistributionDataKey, KeeperBountyDataKey},
 };
 
-pub fn execute(env: Env, token_address: Address) -> Result<(), Error> {
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    keeper: Option<Address>,
+    tag: Option<Symbol>,
+) -> Result<(), Error> {
     if !ConfigDataKey::exists(&env) {
         return Err(Error::NotInitialized);
     };
 
-    // Make sure the caller is the admin
-    ConfigDataKey::require_admin(&env)?;
-
-    let token_client = get_token_client(&env, &token_address);
-
-    // Get the available token balance
-    let balance = token_client.balance(&env.current_contract_address());
-
-    // Get the shareholders vector
-    let shareholders = ShareDataKey::get_shareholders(&env);
-
-    // For each shareholder, calculate the amount of tokens to distribute
-    for shareholder in shareholders.iter() {
-        if let Some(ShareDataKey { share, .. }) = ShareDataKey::get_share(&env, &shareholder) {
-            // Calculate the amount of tokens to distribute
-            let amount = balance.fixed_mul_floor(share, 10000).unwrap_or(0);
-
-            if amount > 0 {
-                // Get the current allocation for the user - default to 0
-                let allocation =
-                    AllocationDataKey::get_allocation(&env, &shareholder, &token_address)
-                        .unwrap_or(0);
-
-                // Update the allocation with the new amount
-                AllocationDataKey::save_allocation(
-                    &env,
-                    &shareholder,
-                    &token_address,
-                    allocation + amount,
-                );
-            }
-        };
-    }
+    // Distributions are executed by the admin unless a keeper is calling
+    let keeper_bounty = match keeper {
+        Some(ref keeper) => {
+            // Make sure the caller is the keeper
+            keeper.require_auth();
+
+            // Only the admin can route the unused balance to a tagged share table
+            if tag.is_some() {
+                return Err(Error::Unauthorized);
+            };
+
+            // Keepers can only distribute if a bounty is configured
+            let keeper_bounty = match KeeperBountyDataKey::get(&env) {
+                Some(keeper_bounty) => keeper_bounty,
+                None => return Err(Error::KeeperDistributionDisabled),
+            };
+
+            // Make sure enough ledgers have passed since the last distribution
+            if let Some(last_distribution) =
+                DistributionDataKey::get_last_distribution(&env, &token_address)
+            {
+                if env.ledger().sequence()
+                    < last_distribution.saturating_add(keeper_bounty.min_interval)
+                {
+                    return Err(Error::DistributionIntervalNotReached);
+                };
+            };
+
+            Some(keeper_bounty)
+        }
+        None => {
+            // Make sure the caller is the admin
+            ConfigDataKey::require_admin(&env)?;
+            None
+        }
+    };
+
+    // Activate the scheduled share tables that are due
+    promote_scheduled_shares(&env);
+
+    // Only the unused balance is distributed, allocated tokens are already owned by the shareholders
+    let unused_balance = get_unused_balance(&env, &token_address);
+
+    let distribution =
+        calculate_distribution(&env, &token_address, unused_balance, &keeper_bounty, &tag)?;
+
+    execute_distribution(&env, &distribution, &keeper, 0);
+
+    // Keep track of the distribution ledger for the keeper interval, deposits and cascades
+    // are permissionless and must not delay the keepers
+    DistributionDataKey::save_last_distribution(&env, &token_address, env.ledger().sequence());
 
     Ok(())
 }
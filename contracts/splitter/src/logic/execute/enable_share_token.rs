@@ -0,0 +1,29 @@
+use soroban_sdk::{Env, String};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn execute(env: Env, name: String, symbol: String) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // The share token cannot be replaced once wallets hold it
+    if ShareTokenDataKey::get(&env).is_some() {
+        return Err(Error::ShareTokenAlreadyEnabled);
+    };
+
+    ShareTokenDataKey::save(&env, &ShareTokenDataKey { name, symbol });
+
+    Ok(())
+}
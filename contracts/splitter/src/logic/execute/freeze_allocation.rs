@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    storage::{ConfigDataKey, FreezeDataKey, MAX_FREEZE_DURATION},
+};
+
+pub fn execute(env: Env, shareholder: Address, duration: u32) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the arbiter
+    ConfigDataKey::require_arbiter(&env)?;
+
+    // Freeze duration must be positive and within the maximum duration
+    if duration == 0 || duration > MAX_FREEZE_DURATION {
+        return Err(Error::InvalidFreezeDuration);
+    };
+
+    // A freeze keeps the start of the dispute until the last freeze has been over for the
+    // maximum duration, so extending, unfreezing or letting it expire cannot reset the limit
+    let ledger = env.ledger().sequence();
+    let frozen_at = match FreezeDataKey::get_last_freeze(&env, &shareholder) {
+        Some(freeze) if ledger < freeze.frozen_until.saturating_add(MAX_FREEZE_DURATION) => {
+            freeze.frozen_at
+        }
+        _ => ledger,
+    };
+    let frozen_until = ledger
+        .saturating_add(duration)
+        .min(frozen_at.saturating_add(MAX_FREEZE_DURATION));
+
+    // The dispute cannot be extended past its maximum duration
+    if frozen_until <= ledger {
+        return Err(Error::InvalidFreezeDuration);
+    };
+
+    let freeze = FreezeDataKey {
+        frozen_at,
+        frozen_until,
+    };
+    FreezeDataKey::save_freeze(&env, &shareholder, &freeze);
+
+    events::freeze(&env, &shareholder, &freeze);
+
+    Ok(())
+}
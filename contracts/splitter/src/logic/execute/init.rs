@@ -2,8 +2,8 @@ use soroban_sdk::{Address, Env, Vec};
 
 use crate::{
     errors::Error,
-    logic::helpers::{check_shares, update_shares},
-    storage::{ConfigDataKey, ShareDataKey},
+    logic::helpers::{check_fee, check_shares, update_shares},
+    storage::{ConfigDataKey, FeeDataKey, ShareDataKey},
 };
 
 pub fn execute(
@@ -11,19 +11,31 @@ pub fn execute(
     admin: Address,
     shares: Vec<ShareDataKey>,
     mutable: bool,
+    fee_recipient: Option<Address>,
+    fee: i128,
+    arbiter: Option<Address>,
 ) -> Result<(), Error> {
     if ConfigDataKey::exists(&env) {
         return Err(Error::AlreadyInitialized);
     };
 
+    // Check if the fee is valid
+    check_fee(&fee_recipient, fee)?;
+
     // Initialize the contract configuration
     ConfigDataKey::init(&env, admin, mutable);
+    if let Some(arbiter) = arbiter {
+        ConfigDataKey::save_arbiter(&env, &arbiter);
+    };
+    if let Some(recipient) = fee_recipient {
+        FeeDataKey::save(&env, &FeeDataKey { recipient, fee });
+    };
 
     // Check if the shares sum up to 10000
     check_shares(&shares)?;
 
     // Update the shares of the shareholders
-    update_shares(&env, &shares);
+    update_shares(&env, &shares, env.ledger().sequence());
 
     Ok(())
 }
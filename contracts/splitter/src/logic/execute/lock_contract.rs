@@ -1,6 +1,10 @@
-use soroban_sdk::Env;
+use soroban_sdk::{Env, Vec};
 
-use crate::{errors::Error, storage::ConfigDataKey};
+use crate::{
+    errors::Error,
+    logic::helpers::promote_scheduled_shares,
+    storage::{ConfigDataKey, ScheduledSharesDataKey},
+};
 
 pub fn execute(env: Env) -> Result<(), Error> {
     if !ConfigDataKey::exists(&env) {
@@ -10,6 +14,11 @@ pub fn execute(env: Env) -> Result<(), Error> {
     // Make sure the caller is the admin
     ConfigDataKey::require_admin(&env)?;
 
+    // Activate the share tables that are due and drop the upcoming ones,
+    // the shares cannot change once the contract is locked
+    promote_scheduled_shares(&env);
+    ScheduledSharesDataKey::save_schedule(&env, &Vec::new(&env));
+
     // Update the contract configuration
     ConfigDataKey::lock_contract(&env);
 
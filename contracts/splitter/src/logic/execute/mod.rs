@@ -1,13 +1,87 @@
+mod adjust_allocation;
+mod approve;
+mod approve_operator;
+mod cancel_scheduled_shares;
+mod cascade_tokens;
+mod claim_merkle;
+mod claim_with_signature;
+mod clawback_allocation;
+mod deposit;
 mod distribute_tokens;
+mod enable_share_token;
+mod freeze_allocation;
 mod init;
 mod lock_contract;
+mod post_merkle_root;
+mod reclaim_allocation;
+mod record_advance;
+mod recover_merkle_funds;
+mod remove_tagged_shares;
+mod renounce_share;
+mod revoke_operator;
+mod schedule_shares;
+mod transfer;
+mod transfer_from;
+mod transfer_share;
 mod transfer_tokens;
+mod unfreeze_allocation;
+mod update_callback;
+mod update_cascade;
+mod update_claim_window;
+mod update_fee;
+mod update_fixed_amounts;
+mod update_keeper_bounty;
+mod update_payout_address;
+mod update_payout_cap;
+mod update_settlement_tokens;
 mod update_shares;
+mod update_shares_transferable;
+mod update_stream_duration;
+mod update_tagged_shares;
+mod update_tiers;
+mod update_token_shares;
 mod withdraw_allocation;
 
+pub use adjust_allocation::execute as adjust_allocation;
+pub use approve::execute as approve;
+pub use approve_operator::execute as approve_operator;
+pub use cancel_scheduled_shares::execute as cancel_scheduled_shares;
+pub use cascade_tokens::execute as cascade_tokens;
+pub use claim_merkle::execute as claim_merkle;
+pub use claim_with_signature::execute as claim_with_signature;
+pub use clawback_allocation::execute as clawback_allocation;
+pub use deposit::execute as deposit;
 pub use distribute_tokens::execute as distribute_tokens;
+pub use enable_share_token::execute as enable_share_token;
+pub use freeze_allocation::execute as freeze_allocation;
 pub use init::execute as init;
 pub use lock_contract::execute as lock_contract;
+pub use post_merkle_root::execute as post_merkle_root;
+pub use reclaim_allocation::execute as reclaim_allocation;
+pub use record_advance::execute as record_advance;
+pub use recover_merkle_funds::execute as recover_merkle_funds;
+pub use remove_tagged_shares::execute as remove_tagged_shares;
+pub use renounce_share::execute as renounce_share;
+pub use revoke_operator::execute as revoke_operator;
+pub use schedule_shares::execute as schedule_shares;
+pub use transfer::execute as transfer;
+pub use transfer_from::execute as transfer_from;
+pub use transfer_share::execute as transfer_share;
 pub use transfer_tokens::execute as transfer_tokens;
+pub use unfreeze_allocation::execute as unfreeze_allocation;
+pub use update_callback::execute as update_callback;
+pub use update_cascade::execute as update_cascade;
+pub use update_claim_window::execute as update_claim_window;
+pub use update_fee::execute as update_fee;
+pub use update_fixed_amounts::execute as update_fixed_amounts;
+pub use update_keeper_bounty::execute as update_keeper_bounty;
+pub use update_payout_address::execute as update_payout_address;
+pub use update_payout_cap::execute as update_payout_cap;
+pub use update_settlement_tokens::execute as update_settlement_tokens;
 pub use update_shares::execute as update_shares;
+pub use update_shares_transferable::execute as update_shares_transferable;
+pub use update_stream_duration::execute as update_stream_duration;
+pub use update_tagged_shares::execute as update_tagged_shares;
+pub use update_tiers::execute as update_tiers;
+pub use update_token_shares::execute as update_token_shares;
 pub use withdraw_allocation::execute as withdraw_allocation;
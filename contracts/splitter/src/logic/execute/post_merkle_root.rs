@@ -0,0 +1,52 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    logic::helpers::{get_unused_balance, reserve_amount},
+    storage::{ConfigDataKey, MerkleRootDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    root: BytesN<32>,
+    total: i128,
+    deadline: u32,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Reserved amount cannot be equal and less than 0
+    if total <= 0 {
+        return Err(Error::ZeroTransferAmount);
+    };
+    // Reserved amount cannot be greater than the unused balance
+    if total > get_unused_balance(&env, &token_address) {
+        return Err(Error::TransferAmountAboveUnusedBalance);
+    };
+    // Recipients need time to claim
+    if deadline < env.ledger().sequence() {
+        return Err(Error::DeadlinePassed);
+    };
+
+    // Reserve the claimable amount so that it is not distributed or transferred
+    reserve_amount(&env, &token_address, total);
+
+    let merkle_root = MerkleRootDataKey {
+        root,
+        total,
+        claimed: 0,
+        deadline,
+        recovered: false,
+    };
+    let epoch = MerkleRootDataKey::add_root(&env, &token_address, &merkle_root);
+
+    events::merkle_root(&env, &token_address, epoch, &merkle_root);
+
+    Ok(())
+}
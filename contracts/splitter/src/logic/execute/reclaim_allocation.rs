@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    logic::helpers::{calculate_distribution, execute_distribution, promote_scheduled_shares},
+    storage::{AllocationDataKey, ConfigDataKey, FreezeDataKey, ReclaimMode, StreamDataKey},
+};
+
+pub fn execute(env: Env, shareholder: Address, token_address: Address) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let config = ConfigDataKey::get(&env).unwrap();
+
+    // Frozen allocations cannot be reclaimed until the freeze is lifted or expires
+    if FreezeDataKey::is_frozen(&env, &shareholder) {
+        return Err(Error::AllocationFrozen);
+    };
+
+    // Make sure there is an allocation to reclaim
+    let allocation =
+        AllocationDataKey::get_allocation(&env, &shareholder, &token_address).unwrap_or(0);
+    if allocation <= 0 {
+        return Err(Error::ZeroWithdrawalAmount);
+    };
+
+    // Allocations can only be reclaimed once their claim window has passed
+    let last_claim = AllocationDataKey::get_last_claim(&env, &shareholder, &token_address);
+    match last_claim {
+        Some(last_claim) if config.claim_window > 0 => {
+            if env.ledger().sequence() <= last_claim.saturating_add(config.claim_window) {
+                return Err(Error::DeadlineNotReached);
+            };
+        }
+        _ => return Err(Error::DeadlineNotReached),
+    };
+
+    // Amounts still locked in streams are not reclaimed, they stay with the shareholder
+    let locked = StreamDataKey::get_locked_amount(&env, &shareholder, &token_address);
+    let reclaimed = allocation - locked;
+    if reclaimed <= 0 {
+        return Err(Error::ZeroWithdrawalAmount);
+    };
+
+    if locked > 0 {
+        AllocationDataKey::save_allocation(&env, &shareholder, &token_address, locked);
+        AllocationDataKey::restart_claim_window(&env, &shareholder, &token_address);
+    } else {
+        AllocationDataKey::remove_allocation(&env, &shareholder, &token_address);
+        AllocationDataKey::remove_last_claim(&env, &shareholder, &token_address);
+        StreamDataKey::remove_streams(&env, &shareholder, &token_address);
+    };
+
+    // Distribute the allocation again, the part of the reclaimed shareholder is left unused
+    if config.reclaim_mode == ReclaimMode::Redistribute {
+        // Activate the scheduled share tables that are due
+        promote_scheduled_shares(&env);
+
+        let mut distribution =
+            calculate_distribution(&env, &token_address, reclaimed, &None, &None)?;
+        if let Some(index) = distribution
+            .allocations
+            .iter()
+            .position(|allocation| allocation.shareholder == shareholder)
+        {
+            let excluded = distribution.allocations.get(index as u32).unwrap();
+            distribution.allocations.remove(index as u32);
+            distribution.amount -= excluded.amount;
+        };
+
+        execute_distribution(&env, &distribution, &None, 0);
+    };
+
+    events::reclaim(&env, &token_address, &shareholder, reclaimed);
+
+    Ok(())
+}
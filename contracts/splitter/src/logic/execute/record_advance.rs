@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, RecoupmentDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+    amount: i128,
+    recipient: Address,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Advance amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::InvalidAdvanceAmount);
+    };
+
+    // Outstanding advances can only be increased for the same recipient
+    if let Some(recoupment) = RecoupmentDataKey::get_recoupment(&env, &shareholder, &token_address)
+    {
+        if recoupment.recipient != recipient {
+            return Err(Error::RecoupmentRecipientMismatch);
+        };
+    };
+
+    RecoupmentDataKey::add_advance(&env, &shareholder, &token_address, &recipient, amount);
+
+    Ok(())
+}
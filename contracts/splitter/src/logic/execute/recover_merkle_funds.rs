@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::reserve_amount,
+    storage::{ConfigDataKey, MerkleRootDataKey},
+};
+
+pub fn execute(env: Env, token_address: Address, epoch: u32) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    let mut merkle_root = match MerkleRootDataKey::get_root(&env, &token_address, epoch) {
+        Some(merkle_root) => merkle_root,
+        None => return Err(Error::MerkleRootNotFound),
+    };
+
+    // Recipients can claim until the deadline
+    if env.ledger().sequence() <= merkle_root.deadline {
+        return Err(Error::DeadlineNotReached);
+    };
+
+    // The unclaimed amount can only be recovered once
+    if merkle_root.recovered {
+        return Err(Error::MerkleAlreadyRecovered);
+    };
+
+    // Release the unclaimed amount back to the unused balance
+    reserve_amount(
+        &env,
+        &token_address,
+        -(merkle_root.total - merkle_root.claimed),
+    );
+
+    merkle_root.recovered = true;
+    MerkleRootDataKey::save_root(&env, &token_address, epoch, &merkle_root);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareTable, ShareVersionDataKey, TagDataKey},
+};
+
+pub fn execute(env: Env, tag: Symbol) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Make sure the tag has a share table
+    if TagDataKey::get_shares(&env, &tag).is_none() {
+        return Err(Error::TagNotFound);
+    };
+
+    TagDataKey::remove_shares(&env, &tag);
+
+    // Keep the removal for the history as an empty share table
+    ShareVersionDataKey::add_version(
+        &env,
+        &ShareTable::Tag(tag),
+        &Vec::new(&env),
+        env.ledger().sequence(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{
+        check_shares, check_tiers, get_shares, promote_scheduled_shares, reset_shares,
+        settle_pending_distributions, update_shares,
+    },
+    storage::{
+        ConfigDataKey, ScheduledSharesDataKey, ShareDataKey, ShareTable, ShareVersionDataKey,
+        TagDataKey, TierDataKey, TokenSharesDataKey,
+    },
+};
+
+pub fn execute(env: Env, shareholder: Address) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    // Locked contracts only allow share changes if the config permits it
+    let config = ConfigDataKey::get(&env).unwrap();
+    if ConfigDataKey::is_contract_locked(&env) && !config.shares_transferable {
+        return Err(Error::ContractLocked);
+    };
+
+    // Activate the scheduled share tables that are due before the share is renounced
+    promote_scheduled_shares(&env);
+
+    // The renounced share is split in every share table naming the shareholder,
+    // all of them are checked before anything is settled or saved
+    let new_shares = renounce_in_table(&env, &get_shares(&env), &shareholder)?;
+    if let Some(new_shares) = &new_shares {
+        check_shares(new_shares)?;
+    };
+    let mut found = new_shares.is_some();
+
+    let mut tags = Vec::new(&env);
+    for tag in TagDataKey::get_tags(&env).iter() {
+        let tagged = TagDataKey::get_shares(&env, &tag).unwrap();
+        if let Some(tagged) = renounce_in_table(&env, &tagged, &shareholder)? {
+            check_shares(&tagged)?;
+            tags.push_back((tag, tagged));
+            found = true;
+        };
+    }
+    let mut token_tables = Vec::new(&env);
+    for token_address in TokenSharesDataKey::get_tokens(&env).iter() {
+        let token_shares = TokenSharesDataKey::get_shares(&env, &token_address).unwrap();
+        if let Some(token_shares) = renounce_in_table(&env, &token_shares, &shareholder)? {
+            check_shares(&token_shares)?;
+            token_tables.push_back((token_address, token_shares));
+            found = true;
+        };
+    }
+    let mut schedule = ScheduledSharesDataKey::get_schedule(&env);
+    for (index, mut scheduled) in schedule.clone().iter().enumerate() {
+        if let Some(shares) = renounce_in_table(&env, &scheduled.shares, &shareholder)? {
+            check_shares(&shares)?;
+            scheduled.shares = shares;
+            schedule.set(index as u32, scheduled);
+            found = true;
+        };
+    }
+    let mut tiers = TierDataKey::get_tiers(&env);
+    for (index, mut tier) in tiers.clone().iter().enumerate() {
+        if let Some(shares) = renounce_in_table(&env, &tier.shares, &shareholder)? {
+            tier.shares = shares;
+            tiers.set(index as u32, tier);
+            found = true;
+        };
+    }
+    check_tiers(&tiers)?;
+
+    // Make sure the caller is in one of the share tables
+    if !found {
+        return Err(Error::ShareholderNotFound);
+    };
+
+    // Revenue earned before leaving is distributed with the current shares,
+    // only the tokens listed by the shareholder are settled
+    settle_pending_distributions(&env, &vec![&env, shareholder.clone()])?;
+
+    let ledger = env.ledger().sequence();
+    for (tag, tagged) in tags.iter() {
+        TagDataKey::save_shares(&env, &tag, &tagged);
+        ShareVersionDataKey::add_version(&env, &ShareTable::Tag(tag), &tagged, ledger);
+    }
+    for (token_address, token_shares) in token_tables.iter() {
+        TokenSharesDataKey::save_shares(&env, &token_address, &token_shares);
+        ShareVersionDataKey::add_version(
+            &env,
+            &ShareTable::Token(token_address),
+            &token_shares,
+            ledger,
+        );
+    }
+    ScheduledSharesDataKey::save_schedule(&env, &schedule);
+    TierDataKey::save_tiers(&env, &tiers);
+
+    // Existing allocations stay with the shareholder until they are withdrawn
+    if let Some(new_shares) = new_shares {
+        reset_shares(&env);
+        update_shares(&env, &new_shares, ledger);
+    };
+
+    Ok(())
+}
+
+/// Removes the shareholder from a share table, splitting the renounced share
+///
+/// Returns None if the shareholder is not in the share table.
+fn renounce_in_table(
+    env: &Env,
+    shares: &Vec<ShareDataKey>,
+    shareholder: &Address,
+) -> Result<Option<Vec<ShareDataKey>>, Error> {
+    let renounced = match shares
+        .iter()
+        .find(|share| share.shareholder == *shareholder)
+    {
+        Some(share) => share.share,
+        None => return Ok(None),
+    };
+
+    // The renounced share can only be split if the remaining shareholders hold a share
+    if renounced >= 10000 {
+        return Err(Error::LowShareCount);
+    };
+
+    Ok(Some(redistribute_share(
+        env,
+        shares,
+        shareholder,
+        renounced,
+    )))
+}
+
+/// Splits the renounced share between the remaining shareholders pro rata
+///
+/// Every shareholder gets the floor of their part first. The basis points left by the rounding
+/// go one by one to the largest remainders, ties going to the shareholder listed first.
+fn redistribute_share(
+    env: &Env,
+    shares: &Vec<ShareDataKey>,
+    shareholder: &Address,
+    renounced: i128,
+) -> Vec<ShareDataKey> {
+    let total_remaining = 10000 - renounced;
+
+    let mut new_shares: Vec<ShareDataKey> = Vec::new(env);
+    let mut remainders: Vec<i128> = Vec::new(env);
+    let mut distributed = 0;
+
+    for share in shares.iter() {
+        if share.shareholder == *shareholder {
+            continue;
+        };
+
+        let part = renounced * share.share;
+        distributed += part / total_remaining;
+        remainders.push_back(part % total_remaining);
+        new_shares.push_back(ShareDataKey {
+            shareholder: share.shareholder,
+            share: share.share + part / total_remaining,
+        });
+    }
+
+    for _ in 0..(renounced - distributed) {
+        let mut largest = 0;
+        for (index, remainder) in remainders.iter().enumerate() {
+            if remainder > remainders.get(largest).unwrap() {
+                largest = index as u32;
+            };
+        }
+
+        let mut share = new_shares.get(largest).unwrap();
+        share.share += 1;
+        new_shares.set(largest, share);
+        remainders.set(largest, -1);
+    }
+
+    new_shares
+}
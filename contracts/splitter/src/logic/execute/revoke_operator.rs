@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, OperatorDataKey},
+};
+
+pub fn execute(env: Env, shareholder: Address, operator: Address) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    OperatorDataKey::remove_operator(&env, &shareholder, &operator);
+
+    Ok(())
+}
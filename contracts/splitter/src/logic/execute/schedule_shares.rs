@@ -0,0 +1,53 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_shares,
+    storage::{ConfigDataKey, ScheduledSharesDataKey, ShareDataKey},
+};
+
+pub fn execute(env: Env, activation_ledger: u32, shares: Vec<ShareDataKey>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Share tables can only be scheduled in the future
+    if activation_ledger <= env.ledger().sequence() {
+        return Err(Error::InvalidActivationLedger);
+    };
+
+    // Check if the shares sum up to 10000
+    check_shares(&shares)?;
+
+    // Insert the share table in order, replacing the one scheduled at the same ledger
+    let mut schedule = ScheduledSharesDataKey::get_schedule(&env);
+    let mut index = schedule.len();
+    for (i, scheduled) in schedule.iter().enumerate() {
+        if scheduled.activation_ledger >= activation_ledger {
+            index = i as u32;
+            if scheduled.activation_ledger == activation_ledger {
+                schedule.remove(index);
+            };
+            break;
+        };
+    }
+    schedule.insert(
+        index,
+        ScheduledSharesDataKey {
+            activation_ledger,
+            shares,
+        },
+    );
+
+    ScheduledSharesDataKey::save_schedule(&env, &schedule);
+
+    Ok(())
+}
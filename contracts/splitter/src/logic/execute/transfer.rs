@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    logic::helpers::move_share,
+    storage::{ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn execute(env: Env, from: Address, to: Address, amount: i128) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    from.require_auth();
+
+    // Make sure the shares are exposed as a token
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    // Pending distributions are settled before the share changes hands
+    move_share(&env, &from, &to, amount)?;
+
+    events::transfer(&env, &from, &to, amount);
+
+    Ok(())
+}
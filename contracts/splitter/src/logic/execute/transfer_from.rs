@@ -0,0 +1,51 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    logic::helpers::move_share,
+    storage::{AllowanceDataKey, ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    spender: Address,
+    from: Address,
+    to: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the spender
+    spender.require_auth();
+
+    // Make sure the shares are exposed as a token
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    // Make sure the spender is allowed to transfer the amount
+    let allowance = AllowanceDataKey::get_allowance(&env, &from, &spender);
+    if amount > allowance.amount {
+        return Err(Error::InsufficientAllowance);
+    };
+
+    // Pending distributions are settled before the share changes hands
+    move_share(&env, &from, &to, amount)?;
+
+    AllowanceDataKey::save_allowance(
+        &env,
+        &from,
+        &spender,
+        &AllowanceDataKey {
+            amount: allowance.amount - amount,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+    );
+
+    events::transfer(&env, &from, &to, amount);
+
+    Ok(())
+}
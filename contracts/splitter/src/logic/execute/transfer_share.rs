@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{errors::Error, logic::helpers::move_share, storage::ConfigDataKey};
+
+pub fn execute(env: Env, from: Address, to: Address, amount_bps: i128) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    from.require_auth();
+
+    // Allocations are not moved, they stay with the sender
+    move_share(&env, &from, &to, amount_bps)
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    events,
+    storage::{ConfigDataKey, FreezeDataKey},
+};
+
+pub fn execute(env: Env, shareholder: Address) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the arbiter
+    ConfigDataKey::require_arbiter(&env)?;
+
+    // The freeze ends now but is kept, so that freezing again keeps the start of the dispute
+    if let Some(freeze) = FreezeDataKey::get_freeze(&env, &shareholder) {
+        FreezeDataKey::save_freeze(
+            &env,
+            &shareholder,
+            &FreezeDataKey {
+                frozen_at: freeze.frozen_at,
+                frozen_until: env.ledger().sequence(),
+            },
+        );
+    };
+
+    events::unfreeze(&env, &shareholder);
+
+    Ok(())
+}
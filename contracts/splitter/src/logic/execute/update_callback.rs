@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{CallbackDataKey, ConfigDataKey},
+};
+
+pub fn execute(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    CallbackDataKey::save_enabled(&env, &shareholder, enabled);
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{CascadeDataKey, ConfigDataKey},
+};
+
+pub fn execute(env: Env, shareholder: Address, enabled: bool) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    CascadeDataKey::save_enabled(&env, &shareholder, enabled);
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ReclaimMode},
+};
+
+pub fn execute(env: Env, claim_window: u32, reclaim_mode: ReclaimMode) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Update the contract configuration
+    ConfigDataKey::set_claim_window(&env, claim_window, reclaim_mode);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_fee,
+    storage::{ConfigDataKey, FeeDataKey},
+};
+
+pub fn execute(env: Env, fee_recipient: Option<Address>, fee: i128) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Check if the fee is valid
+    check_fee(&fee_recipient, fee)?;
+
+    // Update the fee of the distributions
+    match fee_recipient {
+        Some(recipient) => FeeDataKey::save(&env, &FeeDataKey { recipient, fee }),
+        None => FeeDataKey::remove(&env),
+    };
+
+    Ok(())
+}
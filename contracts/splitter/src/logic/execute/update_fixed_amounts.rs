@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_fixed_amounts,
+    storage::{ConfigDataKey, FixedAmountDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    fixed_amounts: Vec<FixedAmountDataKey>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Check if the fixed amounts are valid
+    check_fixed_amounts(&fixed_amounts)?;
+
+    // Update the fixed amounts for the token
+    FixedAmountDataKey::save_fixed_amounts(&env, &token_address, &fixed_amounts);
+
+    Ok(())
+}
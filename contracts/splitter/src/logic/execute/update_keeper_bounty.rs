@@ -0,0 +1,38 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, KeeperBountyDataKey},
+};
+
+pub fn execute(env: Env, keeper_bounty: Option<KeeperBountyDataKey>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Bounty fee has to be between 0 and 10000 and the cap cannot be negative
+    if let Some(ref keeper_bounty) = keeper_bounty {
+        if !(0..=10000).contains(&keeper_bounty.fee) {
+            return Err(Error::InvalidKeeperBounty);
+        };
+        if keeper_bounty.cap.unwrap_or(0) < 0 {
+            return Err(Error::InvalidKeeperBounty);
+        };
+    };
+
+    // Update or remove the keeper bounty
+    match keeper_bounty {
+        Some(keeper_bounty) => KeeperBountyDataKey::save(&env, &keeper_bounty),
+        None => KeeperBountyDataKey::remove(&env),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, PayoutAddressDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    payout_address: Option<Address>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    match payout_address {
+        Some(payout_address) if payout_address != shareholder => {
+            PayoutAddressDataKey::save_payout_address(&env, &shareholder, &payout_address)
+        }
+        _ => PayoutAddressDataKey::remove_payout_address(&env, &shareholder),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, PayoutCapDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+    cap: Option<i128>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Update or remove the payout cap
+    match cap {
+        Some(cap) => {
+            // Payout cap cannot be negative
+            if cap < 0 {
+                return Err(Error::InvalidPayoutCap);
+            };
+            PayoutCapDataKey::save_cap(&env, &shareholder, &token_address, cap);
+        }
+        None => PayoutCapDataKey::remove_cap(&env, &shareholder, &token_address),
+    };
+
+    Ok(())
+}
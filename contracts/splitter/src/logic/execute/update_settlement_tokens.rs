@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, SettlementTokensDataKey, MAX_SETTLEMENT_TOKENS},
+};
+
+pub fn execute(env: Env, shareholder: Address, tokens: Vec<Address>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    // Keep the settlement of share changes bounded
+    if tokens.len() > MAX_SETTLEMENT_TOKENS {
+        return Err(Error::TooManySettlementTokens);
+    };
+
+    if tokens.is_empty() {
+        SettlementTokensDataKey::remove_tokens(&env, &shareholder);
+    } else {
+        SettlementTokensDataKey::save_tokens(&env, &shareholder, &tokens);
+    };
+
+    Ok(())
+}
@@ -2,7 +2,9 @@ use soroban_sdk::{Env, Vec};
 
 use crate::{
     errors::Error,
-    logic::helpers::{check_shares, reset_shares, update_shares as update_shares_helper},
+    logic::helpers::{
+        check_shares, promote_scheduled_shares, reset_shares, update_shares as update_shares_helper,
+    },
     storage::{ConfigDataKey, ShareDataKey},
 };
 
@@ -14,14 +16,22 @@ pub fn execute(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
     // Make sure the caller is the admin
     ConfigDataKey::require_admin(&env)?;
 
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
     // Check if the shares sum up to 10000
     check_shares(&shares)?;
 
+    // Activate the scheduled share tables that are due before they are overwritten
+    promote_scheduled_shares(&env);
+
     // Remove all of the shareholders and their shares
     reset_shares(&env);
 
     // Update the shares of the shareholders
-    update_shares_helper(&env, &shares);
+    update_shares_helper(&env, &shares, env.ledger().sequence());
 
     Ok(())
 }
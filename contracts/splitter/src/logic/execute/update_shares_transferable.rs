@@ -0,0 +1,22 @@
+use soroban_sdk::Env;
+
+use crate::{errors::Error, storage::ConfigDataKey};
+
+pub fn execute(env: Env, shares_transferable: bool) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Update the contract configuration
+    ConfigDataKey::set_shares_transferable(&env, shares_transferable);
+
+    Ok(())
+}
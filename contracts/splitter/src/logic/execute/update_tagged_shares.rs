@@ -0,0 +1,37 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_shares,
+    storage::{ConfigDataKey, ShareDataKey, ShareTable, ShareVersionDataKey, TagDataKey},
+};
+
+pub fn execute(env: Env, tag: Symbol, shares: Vec<ShareDataKey>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Check if the shares sum up to 10000
+    check_shares(&shares)?;
+
+    // Update the share table of the tag
+    TagDataKey::save_shares(&env, &tag, &shares);
+
+    // Keep the share table for the history
+    ShareVersionDataKey::add_version(
+        &env,
+        &ShareTable::Tag(tag),
+        &shares,
+        env.ledger().sequence(),
+    );
+
+    Ok(())
+}
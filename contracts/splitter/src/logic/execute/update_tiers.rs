@@ -0,0 +1,64 @@
+use soroban_sdk::{Address, Env, Map, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_tiers,
+    storage::{ConfigDataKey, TierDataKey},
+};
+
+pub fn execute(env: Env, tiers: Vec<TierDataKey>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    // Check if the tier shares sum up to 10000 and the caps are valid
+    check_tiers(&tiers)?;
+
+    // Collect the fill progress of the previous tiers, it is tracked by the position of the tier
+    let previous_tiers = TierDataKey::get_tiers(&env);
+    let mut previous_fills: Vec<Map<Address, i128>> = Vec::new(&env);
+    for (index, tier) in previous_tiers.iter().enumerate() {
+        let mut fills = Map::new(&env);
+        for token in tier.caps.keys().iter() {
+            fills.set(
+                token.clone(),
+                TierDataKey::get_filled(&env, index as u32, &token),
+            );
+            TierDataKey::remove_filled(&env, index as u32, &token);
+        }
+        previous_fills.push_back(fills);
+    }
+
+    // Tiers with an unchanged share table keep their fill progress, even if they moved
+    let mut matched: Vec<u32> = Vec::new(&env);
+    for (index, tier) in tiers.iter().enumerate() {
+        let previous = previous_tiers
+            .iter()
+            .enumerate()
+            .position(|(previous, other)| {
+                other.shares == tier.shares && !matched.contains(previous as u32)
+            });
+        if let Some(previous) = previous {
+            matched.push_back(previous as u32);
+            let fills = previous_fills.get(previous as u32).unwrap();
+            for token in tier.caps.keys().iter() {
+                if let Some(filled) = fills.get(token.clone()) {
+                    TierDataKey::save_filled(&env, index as u32, &token, filled);
+                };
+            }
+        };
+    }
+
+    // Update the tiers
+    TierDataKey::save_tiers(&env, &tiers);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::check_shares,
+    storage::{ConfigDataKey, ShareDataKey, ShareTable, ShareVersionDataKey, TokenSharesDataKey},
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    shares: Option<Vec<ShareDataKey>>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the admin
+    ConfigDataKey::require_admin(&env)?;
+
+    // Make sure the contract is not locked
+    if ConfigDataKey::is_contract_locked(&env) {
+        return Err(Error::ContractLocked);
+    };
+
+    let shares = match shares {
+        Some(shares) => {
+            // Check if the shares sum up to 10000
+            check_shares(&shares)?;
+
+            TokenSharesDataKey::save_shares(&env, &token_address, &shares);
+            shares
+        }
+        None => {
+            TokenSharesDataKey::remove_shares(&env, &token_address);
+            Vec::new(&env)
+        }
+    };
+
+    // Keep the share table for the history, a removed override is kept as an empty share table
+    ShareVersionDataKey::add_version(
+        &env,
+        &ShareTable::Token(token_address),
+        &shares,
+        env.ledger().sequence(),
+    );
+
+    Ok(())
+}
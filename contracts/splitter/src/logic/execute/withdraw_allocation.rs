@@ -2,8 +2,8 @@ use soroban_sdk::{Address, Env};
 
 use crate::{
     errors::Error,
-    logic::helpers::get_token_client,
-    storage::{AllocationDataKey, ConfigDataKey},
+    logic::helpers::{debit_allocation, get_token_client},
+    storage::{ConfigDataKey, OperatorDataKey, PayoutAddressDataKey},
 };
 
 pub fn execute(
@@ -11,37 +11,45 @@ pub fn execute(
     token_address: Address,
     shareholder: Address,
     amount: i128,
+    operator: Option<Address>,
 ) -> Result<(), Error> {
     if !ConfigDataKey::exists(&env) {
         return Err(Error::NotInitialized);
     };
 
-    // Make sure the caller is the shareholder
-    shareholder.require_auth();
-
-    let token_client = get_token_client(&env, &token_address);
-
-    // Get the current allocation for the user - default to 0
-    let allocation =
-        AllocationDataKey::get_allocation(&env, &shareholder, &token_address).unwrap_or(0);
-
-    // Withdraw amount cannot be equal and less than 0
-    if amount == 0 {
-        return Err(Error::ZeroWithdrawalAmount);
+    // Make sure the caller is the shareholder or an operator approved by the shareholder
+    let approval = match operator {
+        Some(ref operator) => {
+            operator.require_auth();
+
+            match OperatorDataKey::get_operator(&env, &shareholder, operator) {
+                Some(approval) => Some(approval),
+                None => return Err(Error::OperatorNotApproved),
+            }
+        }
+        None => {
+            shareholder.require_auth();
+            None
+        }
     };
-    // Withdraw amount cannot be greater than the allocation
-    if amount > allocation {
-        return Err(Error::WithdrawalAmountAboveAllocation);
+
+    // Withdraw amount cannot be greater than the remaining limit of the operator for the token
+    if let (Some(operator), Some(mut approval)) = (operator, approval) {
+        if let Some(limit) = approval.limits.get(token_address.clone()) {
+            if amount > limit {
+                return Err(Error::WithdrawalAmountAboveOperatorLimit);
+            };
+            approval.limits.set(token_address.clone(), limit - amount);
+            OperatorDataKey::save_operator(&env, &shareholder, &operator, &approval);
+        };
     };
 
-    if amount == allocation {
-        AllocationDataKey::remove_allocation(&env, &shareholder, &token_address);
-    } else {
-        AllocationDataKey::save_allocation(&env, &shareholder, &token_address, allocation - amount);
-    }
+    debit_allocation(&env, &shareholder, &token_address, amount)?;
 
-    // Transfer the tokens to the shareholder
-    token_client.transfer(&env.current_contract_address(), &shareholder, &amount);
+    // Transfer the tokens to the payout address of the shareholder
+    let token_client = get_token_client(&env, &token_address);
+    let payout_address = PayoutAddressDataKey::get_payout_address(&env, &shareholder);
+    token_client.transfer(&env.current_contract_address(), &payout_address, &amount);
 
     Ok(())
 }
@@ -1,9 +1,27 @@
+use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
     token::{self, TokenClient},
-    Address, Env, Vec,
+    vec,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
-use crate::{errors::Error, storage::ShareDataKey};
+use crate::{
+    errors::Error,
+    events,
+    storage::{
+        Allocation, AllocationDataKey, CallbackDataKey, CascadeDataKey, ConfigDataKey,
+        CorrectionDataKey, CorrectionKind, Distribution, FeeDataKey, FixedAmountDataKey,
+        FreezeDataKey, KeeperBountyDataKey, PayoutAddressDataKey, PayoutCapDataKey, Recoupment,
+        RecoupmentDataKey, ScheduledSharesDataKey, SettlementTokensDataKey, ShareDataKey,
+        ShareTable, ShareVersionDataKey, StreamDataKey, TagDataKey, TierDataKey,
+        TokenSharesDataKey, MAX_CASCADE_DEPTH,
+    },
+};
+
+/// Prefix of the payloads signed for the relayed claims
+const CLAIM_DOMAIN: &[u8] = b"sorosplits:claim_with_signature";
 
 /// Checks if the shares sum up to 10000
 pub fn check_shares(shares: &Vec<ShareDataKey>) -> Result<(), Error> {
@@ -20,21 +38,169 @@ pub fn check_shares(shares: &Vec<ShareDataKey>) -> Result<(), Error> {
     Ok(())
 }
 
+/// Checks if the tiers have shareholders with shares summing up to 10000 and positive caps
+pub fn check_tiers(tiers: &Vec<TierDataKey>) -> Result<(), Error> {
+    for tier in tiers.iter() {
+        if tier.shares.is_empty() {
+            return Err(Error::LowShareCount);
+        };
+
+        let total = tier.shares.iter().fold(0, |acc, share| acc + share.share);
+        if total != 10000 {
+            return Err(Error::InvalidShareTotal);
+        };
+
+        if tier.caps.is_empty() {
+            return Err(Error::InvalidTierCap);
+        };
+        for cap in tier.caps.values().iter() {
+            if cap <= 0 {
+                return Err(Error::InvalidTierCap);
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if the fixed amounts are positive and have distinct recipients
+pub fn check_fixed_amounts(fixed_amounts: &Vec<FixedAmountDataKey>) -> Result<(), Error> {
+    for (index, fixed_amount) in fixed_amounts.iter().enumerate() {
+        if fixed_amount.amount <= 0 {
+            return Err(Error::InvalidFixedAmount);
+        };
+
+        // The payment schedule is kept per recipient
+        if fixed_amounts
+            .iter()
+            .skip(index + 1)
+            .any(|other| other.recipient == fixed_amount.recipient)
+        {
+            return Err(Error::InvalidFixedAmount);
+        };
+    }
+
+    Ok(())
+}
+
+/// Checks if the fee is between 0 and 10000 and has a recipient
+pub fn check_fee(fee_recipient: &Option<Address>, fee: i128) -> Result<(), Error> {
+    if !(0..=10000).contains(&fee) {
+        return Err(Error::InvalidFee);
+    };
+
+    if fee > 0 && fee_recipient.is_none() {
+        return Err(Error::InvalidFee);
+    };
+
+    Ok(())
+}
+
 /// Updates the shares of the shareholders
-pub fn update_shares(env: &Env, shares: &Vec<ShareDataKey>) {
+///
+/// Every update is kept as a new version of the share table that took effect at the ledger.
+pub fn update_shares(env: &Env, shares: &Vec<ShareDataKey>, ledger: u32) {
     // Shareholders are stored in a vector
-    let mut shareholders: Vec<Address> = Vec::new(&env);
+    let mut shareholders: Vec<Address> = Vec::new(env);
 
     for share in shares.iter() {
         // Add the shareholder to the vector
         shareholders.push_back(share.shareholder.clone());
 
         // Store the share for each shareholder
-        ShareDataKey::save_share(&env, share.shareholder, share.share);
+        ShareDataKey::save_share(env, share.shareholder, share.share);
     }
 
     // Store the shareholders vector
-    ShareDataKey::save_shareholders(&env, shareholders);
+    ShareDataKey::save_shareholders(env, shareholders);
+
+    // Keep the share table for the history
+    ShareVersionDataKey::add_version(env, &ShareTable::Default, shares, ledger);
+}
+
+/// Returns all of the shareholders with their shares
+///
+/// Scheduled share tables are returned from their activation ledger even before they are promoted.
+pub fn get_shares(env: &Env) -> Vec<ShareDataKey> {
+    if let Some(scheduled) = get_active_schedule(env) {
+        return scheduled.shares;
+    };
+
+    let mut shares: Vec<ShareDataKey> = Vec::new(env);
+
+    for shareholder in ShareDataKey::get_shareholders(env).iter() {
+        if let Some(share) = ShareDataKey::get_share(env, &shareholder) {
+            shares.push_back(share);
+        };
+    }
+
+    shares
+}
+
+/// Returns the latest scheduled share table that is active
+fn get_active_schedule(env: &Env) -> Option<ScheduledSharesDataKey> {
+    let ledger = env.ledger().sequence();
+    let mut active = None;
+    for scheduled in ScheduledSharesDataKey::get_schedule(env).iter() {
+        if scheduled.activation_ledger > ledger {
+            break;
+        };
+        active = Some(scheduled);
+    }
+    active
+}
+
+/// Replaces the default share table with the latest scheduled share table that is active
+pub fn promote_scheduled_shares(env: &Env) {
+    let ledger = env.ledger().sequence();
+    let schedule = ScheduledSharesDataKey::get_schedule(env);
+
+    let mut active = None;
+    let mut upcoming: Vec<ScheduledSharesDataKey> = Vec::new(env);
+    for scheduled in schedule.iter() {
+        if scheduled.activation_ledger <= ledger {
+            active = Some(scheduled);
+        } else {
+            upcoming.push_back(scheduled);
+        };
+    }
+
+    if let Some(scheduled) = active {
+        reset_shares(env);
+        update_shares(env, &scheduled.shares, scheduled.activation_ledger);
+        ScheduledSharesDataKey::save_schedule(env, &upcoming);
+    };
+}
+
+/// Returns the share table of the token
+///
+/// The override of the token is used if there is one, otherwise the default share table.
+pub fn get_token_shares(env: &Env, token_address: &Address) -> Vec<ShareDataKey> {
+    match TokenSharesDataKey::get_shares(env, token_address) {
+        Some(shares) => shares,
+        None => get_shares(env),
+    }
+}
+
+/// Returns the share table used to split the distribution
+///
+/// The share table of the tag is used if a tag is given, otherwise the share table of the token.
+/// The table is returned along with its source for the distribution record.
+pub fn get_distribution_shares(
+    env: &Env,
+    token_address: &Address,
+    tag: &Option<Symbol>,
+) -> Result<(Vec<ShareDataKey>, ShareTable), Error> {
+    if let Some(tag) = tag {
+        let shares = TagDataKey::get_shares(env, tag).ok_or(Error::TagNotFound)?;
+        return Ok((shares, ShareTable::Tag(tag.clone())));
+    };
+
+    if let Some(shares) = TokenSharesDataKey::get_shares(env, token_address) {
+        return Ok((shares, ShareTable::Token(token_address.clone())));
+    };
+
+    Ok((get_shares(env), ShareTable::Default))
 }
 
 /// Removes all of the shareholders and their shares
@@ -45,6 +211,869 @@ pub fn reset_shares(env: &Env) {
     ShareDataKey::remove_shareholders(env);
 }
 
+/// Moves the share from one shareholder to another, keeping the order of the shareholders
+///
+/// The share is moved in every share table that names the sender: the default share table,
+/// the tagged share tables, the token overrides, the scheduled share tables and the tiers.
+/// Outside of the default share table the same part of the sender's share is moved, rounded down,
+/// so that a transfer of the whole share leaves the sender in none of the tables.
+///
+/// The unused balances of the settlement tokens of both sides are settled with the current shares
+/// first, so that the revenue earned before the transfer stays with the sender.
+pub fn move_share(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    // Locked contracts only allow transfers if the config permits it
+    let config = ConfigDataKey::get(env).unwrap();
+    if ConfigDataKey::is_contract_locked(env) && !config.shares_transferable {
+        return Err(Error::ContractLocked);
+    };
+
+    // Transfer amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::InvalidShareTransferAmount);
+    };
+
+    // Activate the scheduled share tables that are due before the shares are moved
+    promote_scheduled_shares(env);
+
+    let shares = get_shares(env);
+
+    // Make sure the sender has enough share to transfer
+    let from_share = shares
+        .iter()
+        .find(|share| share.shareholder == *from)
+        .map(|share| share.share)
+        .unwrap_or(0);
+    if amount > from_share {
+        return Err(Error::InsufficientShare);
+    };
+
+    // Transferring to self leaves the shares untouched
+    if from == to {
+        return Ok(());
+    };
+
+    settle_pending_distributions(env, &vec![env, from.clone(), to.clone()])?;
+
+    let ledger = env.ledger().sequence();
+    let new_shares = move_table_share(env, &shares, from, to, amount);
+
+    // Check if the shares still sum up to 10000
+    check_shares(&new_shares)?;
+
+    // Move the same part of the sender's share in the other share tables
+    for tag in TagDataKey::get_tags(env).iter() {
+        let tagged = TagDataKey::get_shares(env, &tag).unwrap();
+        if let Some(tagged) = move_part_of_share(env, &tagged, from, to, amount, from_share) {
+            check_shares(&tagged)?;
+            TagDataKey::save_shares(env, &tag, &tagged);
+            ShareVersionDataKey::add_version(env, &ShareTable::Tag(tag), &tagged, ledger);
+        };
+    }
+    for token_address in TokenSharesDataKey::get_tokens(env).iter() {
+        let token_shares = TokenSharesDataKey::get_shares(env, &token_address).unwrap();
+        if let Some(token_shares) =
+            move_part_of_share(env, &token_shares, from, to, amount, from_share)
+        {
+            check_shares(&token_shares)?;
+            TokenSharesDataKey::save_shares(env, &token_address, &token_shares);
+            ShareVersionDataKey::add_version(
+                env,
+                &ShareTable::Token(token_address),
+                &token_shares,
+                ledger,
+            );
+        };
+    }
+    let mut schedule = ScheduledSharesDataKey::get_schedule(env);
+    for (index, mut scheduled) in schedule.clone().iter().enumerate() {
+        if let Some(shares) =
+            move_part_of_share(env, &scheduled.shares, from, to, amount, from_share)
+        {
+            check_shares(&shares)?;
+            scheduled.shares = shares;
+            schedule.set(index as u32, scheduled);
+        };
+    }
+    ScheduledSharesDataKey::save_schedule(env, &schedule);
+    let mut tiers = TierDataKey::get_tiers(env);
+    for (index, mut tier) in tiers.clone().iter().enumerate() {
+        if let Some(shares) = move_part_of_share(env, &tier.shares, from, to, amount, from_share) {
+            tier.shares = shares;
+            tiers.set(index as u32, tier);
+        };
+    }
+    check_tiers(&tiers)?;
+    TierDataKey::save_tiers(env, &tiers);
+
+    reset_shares(env);
+    update_shares(env, &new_shares, ledger);
+
+    Ok(())
+}
+
+/// Moves the same part of the sender's share as in the default share table in another share table
+///
+/// Returns None if the sender has no share in the table or the part rounds down to 0.
+fn move_part_of_share(
+    env: &Env,
+    shares: &Vec<ShareDataKey>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    from_share: i128,
+) -> Option<Vec<ShareDataKey>> {
+    let share = shares.iter().find(|share| share.shareholder == *from)?;
+    let moved = share.share.fixed_mul_floor(amount, from_share).unwrap_or(0);
+    if moved == 0 {
+        return None;
+    };
+    Some(move_table_share(env, shares, from, to, moved))
+}
+
+/// Moves the share from one shareholder to another in a share table
+///
+/// The receiver keeps its position or is appended, the sender is dropped once its share is empty.
+fn move_table_share(
+    env: &Env,
+    shares: &Vec<ShareDataKey>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Vec<ShareDataKey> {
+    let mut new_shares: Vec<ShareDataKey> = Vec::new(env);
+    let mut received = false;
+    for share in shares.iter() {
+        if share.shareholder == *from {
+            if share.share > amount {
+                new_shares.push_back(ShareDataKey {
+                    shareholder: share.shareholder,
+                    share: share.share - amount,
+                });
+            };
+        } else if share.shareholder == *to {
+            new_shares.push_back(ShareDataKey {
+                shareholder: share.shareholder,
+                share: share.share + amount,
+            });
+            received = true;
+        } else {
+            new_shares.push_back(share);
+        };
+    }
+    if !received {
+        new_shares.push_back(ShareDataKey {
+            shareholder: to.clone(),
+            share: amount,
+        });
+    };
+    new_shares
+}
+
+/// Distributes the unused balances of the settlement tokens of the shareholders with the current shares
+///
+/// Only the tokens the shareholders listed themselves are settled, each of them at most once.
+pub fn settle_pending_distributions(env: &Env, shareholders: &Vec<Address>) -> Result<(), Error> {
+    let mut tokens: Vec<Address> = Vec::new(env);
+    for shareholder in shareholders.iter() {
+        for token_address in SettlementTokensDataKey::get_tokens(env, &shareholder).iter() {
+            if !tokens.contains(&token_address) {
+                tokens.push_back(token_address);
+            };
+        }
+    }
+
+    for token_address in tokens.iter() {
+        let unused_balance = get_unused_balance(env, &token_address);
+        if unused_balance <= 0 {
+            continue;
+        };
+
+        let distribution =
+            calculate_distribution(env, &token_address, unused_balance, &None, &None)?;
+        execute_distribution(env, &distribution, &None, 0);
+    }
+
+    Ok(())
+}
+
 pub fn get_token_client<'a>(env: &'a Env, token_address: &Address) -> TokenClient<'a> {
     token::Client::new(env, token_address)
 }
+
+/// Calculates how the amount of tokens is distributed
+///
+/// The fee is carved out first, then the keeper bounty if any, then the fixed amounts that are due.
+/// Fixed amounts are paid in list order. The first one that the remaining amount cannot cover
+/// stops the fixed payments for this distribution, so a later entry is never paid before an earlier one.
+/// Unpaid fixed amounts with a period stay due, those without a period are only owed per distribution.
+/// The remaining amount fills the tiers in order and whatever is left
+/// after the tier caps is split between the shareholders of the tagged or token share table,
+/// respecting their payout caps.
+/// Allocations of shareholders with outstanding advances are diverted to the recoupment recipients.
+///
+/// The scheduled share tables that are due must be promoted first,
+/// so that the distribution records the version it was split with.
+pub fn calculate_distribution(
+    env: &Env,
+    token_address: &Address,
+    amount: i128,
+    keeper_bounty: &Option<KeeperBountyDataKey>,
+    tag: &Option<Symbol>,
+) -> Result<Distribution, Error> {
+    let (shares, share_table) = get_distribution_shares(env, token_address, tag)?;
+
+    let mut remaining = amount;
+
+    // Carve out the fee before the shareholder split
+    let fee = match FeeDataKey::get(env) {
+        Some(fee) => remaining.fixed_mul_floor(fee.fee, 10000).unwrap_or(0),
+        None => 0,
+    };
+    remaining -= fee;
+
+    // Pay the keeper bounty out of the distributed amount
+    let mut bounty = 0;
+    if let Some(keeper_bounty) = keeper_bounty {
+        bounty = remaining
+            .fixed_mul_floor(keeper_bounty.fee, 10000)
+            .unwrap_or(0);
+        if let Some(cap) = keeper_bounty.cap {
+            bounty = bounty.min(cap);
+        };
+    };
+    remaining -= bounty;
+
+    let mut allocations: Vec<Allocation> = Vec::new(env);
+
+    // Pay the fixed amounts that are due before the percentage shares
+    let ledger = env.ledger().sequence();
+    let mut fixed_amounts: Vec<Allocation> = Vec::new(env);
+    for fixed_amount in FixedAmountDataKey::get_fixed_amounts(env, token_address).iter() {
+        if !fixed_amount.is_due(env, token_address, ledger) {
+            continue;
+        }
+        if fixed_amount.amount > remaining {
+            break;
+        }
+        add_allocation(
+            &mut allocations,
+            &fixed_amount.recipient,
+            fixed_amount.amount,
+        );
+        fixed_amounts.push_back(Allocation {
+            shareholder: fixed_amount.recipient,
+            amount: fixed_amount.amount,
+        });
+        remaining -= fixed_amount.amount;
+    }
+
+    // Fill the tiers in order until their caps are reached
+    let mut tiers: Vec<i128> = Vec::new(env);
+    for (index, tier) in TierDataKey::get_tiers(env).iter().enumerate() {
+        let cap = tier.caps.get(token_address.clone()).unwrap_or(0);
+        let filled = TierDataKey::get_filled(env, index as u32, token_address);
+        let tier_amount = remaining.min(cap - filled).max(0);
+
+        split_amount(
+            env,
+            token_address,
+            tier_amount,
+            &tier.shares,
+            &mut allocations,
+        );
+
+        tiers.push_back(tier_amount);
+        remaining -= tier_amount;
+    }
+
+    // Split the rest between the shareholders
+    split_amount(env, token_address, remaining, &shares, &mut allocations);
+
+    // Divert the allocations of the shareholders with outstanding advances
+    let mut recoupments: Vec<Recoupment> = Vec::new(env);
+    for allocation in allocations.iter() {
+        if let Some(recoupment) =
+            RecoupmentDataKey::get_recoupment(env, &allocation.shareholder, token_address)
+        {
+            let amount = allocation.amount.min(recoupment.balance);
+            if amount > 0 {
+                recoupments.push_back(Recoupment {
+                    shareholder: allocation.shareholder,
+                    recipient: recoupment.recipient,
+                    amount,
+                });
+            };
+        };
+    }
+    for recoupment in recoupments.iter() {
+        add_allocation(
+            &mut allocations,
+            &recoupment.shareholder,
+            -recoupment.amount,
+        );
+        add_allocation(&mut allocations, &recoupment.recipient, recoupment.amount);
+    }
+
+    Ok(Distribution {
+        token: token_address.clone(),
+        amount,
+        fee,
+        keeper_bounty: bounty,
+        fixed_amounts,
+        tiers,
+        allocations,
+        recoupments,
+        share_version: ShareVersionDataKey::get_table_version(env, &share_table),
+        share_table,
+    })
+}
+
+/// Splits the amount between the shareholders in proportion to their shares
+///
+/// Shareholders cannot receive more than their payout cap. The excess is spread over
+/// the shareholders that have not reached their cap in proportion to their shares.
+/// The rounding dust is given to the largest shareholder so that nothing is left over.
+fn split_amount(
+    env: &Env,
+    token_address: &Address,
+    amount: i128,
+    shares: &Vec<ShareDataKey>,
+    allocations: &mut Vec<Allocation>,
+) {
+    let mut remaining = amount;
+    let mut active_shares = shares.clone();
+
+    while remaining > 0 && !active_shares.is_empty() {
+        let total_share = active_shares.iter().fold(0, |acc, share| acc + share.share);
+        if total_share <= 0 {
+            break;
+        };
+
+        let mut distributed = 0;
+        let mut uncapped_shares: Vec<ShareDataKey> = Vec::new(env);
+
+        for share in active_shares.iter() {
+            let mut amount = remaining
+                .fixed_mul_floor(share.share, total_share)
+                .unwrap_or(0);
+
+            // Limit the amount to what is left until the payout cap
+            match get_payout_room(env, token_address, &share.shareholder, allocations) {
+                Some(room) => {
+                    if amount < room {
+                        uncapped_shares.push_back(share.clone());
+                    } else {
+                        amount = room;
+                    };
+                }
+                None => uncapped_shares.push_back(share.clone()),
+            };
+
+            add_allocation(allocations, &share.shareholder, amount);
+            distributed += amount;
+        }
+
+        remaining -= distributed;
+
+        // Stop when no shareholder has reached the cap in this round
+        if uncapped_shares.len() == active_shares.len() {
+            if remaining > 0 {
+                let largest = active_shares
+                    .iter()
+                    .reduce(|largest, share| {
+                        if share.share > largest.share {
+                            share
+                        } else {
+                            largest
+                        }
+                    })
+                    .unwrap();
+
+                let dust =
+                    match get_payout_room(env, token_address, &largest.shareholder, allocations) {
+                        Some(room) => remaining.min(room),
+                        None => remaining,
+                    };
+                add_allocation(allocations, &largest.shareholder, dust);
+            };
+            break;
+        };
+        active_shares = uncapped_shares;
+    }
+}
+
+/// Returns what the shareholder can still receive until the payout cap, if any
+fn get_payout_room(
+    env: &Env,
+    token_address: &Address,
+    shareholder: &Address,
+    allocations: &Vec<Allocation>,
+) -> Option<i128> {
+    PayoutCapDataKey::get_cap(env, shareholder, token_address).map(|cap| {
+        let received = PayoutCapDataKey::get_received(env, shareholder, token_address)
+            + get_allocation_amount(allocations, shareholder);
+        (cap - received).max(0)
+    })
+}
+
+/// Returns the amount allocated to the shareholder in the list
+fn get_allocation_amount(allocations: &Vec<Allocation>, shareholder: &Address) -> i128 {
+    allocations
+        .iter()
+        .find(|allocation| allocation.shareholder == *shareholder)
+        .map(|allocation| allocation.amount)
+        .unwrap_or(0)
+}
+
+/// Adds the amount to the allocation of the shareholder in the list
+///
+/// Negative amounts reduce the allocation, which is removed from the list once it reaches 0.
+fn add_allocation(allocations: &mut Vec<Allocation>, shareholder: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    };
+
+    for (index, allocation) in allocations.iter().enumerate() {
+        if allocation.shareholder == *shareholder {
+            let new_amount = allocation.amount + amount;
+            if new_amount == 0 {
+                allocations.remove(index as u32);
+            } else {
+                allocations.set(
+                    index as u32,
+                    Allocation {
+                        shareholder: allocation.shareholder,
+                        amount: new_amount,
+                    },
+                );
+            }
+            return;
+        };
+    }
+
+    if amount > 0 {
+        allocations.push_back(Allocation {
+            shareholder: shareholder.clone(),
+            amount,
+        });
+    };
+}
+
+/// Pulls the tokens from the sender and distributes only the received amount
+///
+/// The unused balance of the contract is left untouched.
+pub fn distribute_received_tokens(
+    env: &Env,
+    from: &Address,
+    token_address: &Address,
+    amount: i128,
+    tag: &Option<Symbol>,
+    depth: u32,
+) -> Result<(), Error> {
+    // Activate the scheduled share tables that are due
+    promote_scheduled_shares(env);
+
+    // Pull the tokens from the sender
+    let token_client = get_token_client(env, token_address);
+    token_client.transfer(from, &env.current_contract_address(), &amount);
+
+    let distribution = calculate_distribution(env, token_address, amount, &None, tag)?;
+
+    execute_distribution(env, &distribution, &None, depth);
+
+    Ok(())
+}
+
+/// Pays the fee and the keeper bounty and allocates the tokens to the shareholders
+pub fn execute_distribution(
+    env: &Env,
+    distribution: &Distribution,
+    keeper: &Option<Address>,
+    depth: u32,
+) {
+    let config = ConfigDataKey::get(env).unwrap();
+    let token_client = get_token_client(env, &distribution.token);
+
+    // Transfer the fee to the fee recipient
+    if distribution.fee > 0 {
+        if let Some(fee) = FeeDataKey::get(env) {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &fee.recipient,
+                &distribution.fee,
+            );
+        };
+    };
+
+    // Transfer the bounty to the keeper
+    if let Some(keeper) = keeper {
+        if distribution.keeper_bounty > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                keeper,
+                &distribution.keeper_bounty,
+            );
+        };
+    };
+
+    let mut credited: Vec<Allocation> = Vec::new(env);
+
+    for Allocation {
+        shareholder,
+        amount,
+    } in distribution.allocations.iter()
+    {
+        // Keep track of the lifetime amount received for the payout caps
+        let received = PayoutCapDataKey::get_received(env, &shareholder, &distribution.token);
+        PayoutCapDataKey::save_received(env, &shareholder, &distribution.token, received + amount);
+
+        // Forward the amount to child splitters instead of crediting it if the cascade succeeds,
+        // frozen shareholders keep accruing their allocations instead
+        if config.stream_duration == 0
+            && CascadeDataKey::is_enabled(env, &shareholder)
+            && !FreezeDataKey::is_frozen(env, &shareholder)
+            && cascade_distribution(
+                env,
+                &distribution.token,
+                &PayoutAddressDataKey::get_payout_address(env, &shareholder),
+                amount,
+                depth,
+            )
+        {
+            continue;
+        };
+
+        // Get the current allocation for the user - default to 0
+        let allocation =
+            AllocationDataKey::get_allocation(env, &shareholder, &distribution.token).unwrap_or(0);
+
+        // Update the allocation with the new amount
+        AllocationDataKey::save_allocation(
+            env,
+            &shareholder,
+            &distribution.token,
+            allocation + amount,
+        );
+        AllocationDataKey::start_claim_window(env, &shareholder, &distribution.token);
+
+        // Release the new amount over time if the distributions are streamed
+        if config.stream_duration > 0 {
+            StreamDataKey::add_stream(
+                env,
+                &shareholder,
+                &distribution.token,
+                amount,
+                config.stream_duration,
+            );
+        }
+
+        credited.push_back(Allocation {
+            shareholder,
+            amount,
+        });
+    }
+
+    for Recoupment {
+        shareholder,
+        recipient,
+        amount,
+    } in distribution.recoupments.iter()
+    {
+        // Reduce the outstanding advance of the shareholder
+        RecoupmentDataKey::recoup(env, &shareholder, &distribution.token, amount);
+
+        // Diverted amounts count as received by the shareholder for the payout caps
+        let received = PayoutCapDataKey::get_received(env, &shareholder, &distribution.token);
+        PayoutCapDataKey::save_received(env, &shareholder, &distribution.token, received + amount);
+        let received = PayoutCapDataKey::get_received(env, &recipient, &distribution.token);
+        PayoutCapDataKey::save_received(env, &recipient, &distribution.token, received - amount);
+    }
+
+    // Keep track of when the fixed amounts were paid
+    let fixed_amounts = FixedAmountDataKey::get_fixed_amounts(env, &distribution.token);
+    for paid in distribution.fixed_amounts.iter() {
+        if let Some(fixed_amount) = fixed_amounts
+            .iter()
+            .find(|fixed_amount| fixed_amount.recipient == paid.shareholder)
+        {
+            fixed_amount.record_payment(env, &distribution.token, env.ledger().sequence());
+        };
+    }
+
+    // Keep track of how far each tier has been filled
+    for (index, tier_amount) in distribution.tiers.iter().enumerate() {
+        if tier_amount > 0 {
+            let filled = TierDataKey::get_filled(env, index as u32, &distribution.token);
+            TierDataKey::save_filled(env, index as u32, &distribution.token, filled + tier_amount);
+        };
+    }
+
+    events::distribution(env, distribution);
+
+    // Notify the recipients that opted in once the distribution is settled
+    for Allocation {
+        shareholder,
+        amount,
+    } in credited.iter()
+    {
+        if CallbackDataKey::is_enabled(env, &shareholder) {
+            notify_allocation(env, &distribution.token, &shareholder, amount);
+        };
+    }
+}
+
+/// Invokes the allocation callback on the recipient
+///
+/// Failing callbacks are ignored so that a single recipient cannot block the distribution.
+fn notify_allocation(env: &Env, token_address: &Address, recipient: &Address, amount: i128) {
+    let _ = env.try_invoke_contract::<Val, Error>(
+        recipient,
+        &Symbol::new(env, "on_split_allocated"),
+        (token_address.clone(), amount).into_val(env),
+    );
+}
+
+/// Checks if the address is a splitter contract through the interface query
+pub fn is_splitter(env: &Env, address: &Address) -> bool {
+    matches!(
+        env.try_invoke_contract::<bool, Error>(
+            address,
+            &Symbol::new(env, "is_splitter"),
+            Vec::new(env)
+        ),
+        Ok(Ok(true))
+    )
+}
+
+/// Forwards the amount to the child splitter and triggers its distribution
+///
+/// The child pulls the amount from the contract, so nothing is transferred if the cascade fails.
+/// Returns whether the amount was cascaded to the child.
+fn cascade_distribution(
+    env: &Env,
+    token_address: &Address,
+    child: &Address,
+    amount: i128,
+    depth: u32,
+) -> bool {
+    if depth >= MAX_CASCADE_DEPTH || !is_splitter(env, child) {
+        return false;
+    };
+
+    // Allow the child to pull the amount from the contract
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_address.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (env.current_contract_address(), child.clone(), amount).into_val(env),
+            },
+            sub_invocations: Vec::new(env),
+        }),
+    ]);
+
+    let res = env.try_invoke_contract::<(), Error>(
+        child,
+        &Symbol::new(env, "cascade_tokens"),
+        (
+            env.current_contract_address(),
+            token_address.clone(),
+            amount,
+            depth + 1,
+        )
+            .into_val(env),
+    );
+    if !matches!(res, Ok(Ok(()))) {
+        return false;
+    };
+
+    events::cascade(env, token_address, child, amount);
+
+    true
+}
+
+/// Removes the withdrawn amount from the allocation of the shareholder
+///
+/// Only the part of the allocation that is streamed so far can be withdrawn.
+pub fn debit_allocation(
+    env: &Env,
+    shareholder: &Address,
+    token_address: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Frozen allocations cannot be withdrawn until the freeze is lifted or expires
+    if FreezeDataKey::is_frozen(env, shareholder) {
+        return Err(Error::AllocationFrozen);
+    };
+
+    // Get the current allocation for the user - default to 0
+    let allocation =
+        AllocationDataKey::get_allocation(env, shareholder, token_address).unwrap_or(0);
+
+    // Withdraw amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::ZeroWithdrawalAmount);
+    };
+    // Withdraw amount cannot be greater than the allocation
+    if amount > allocation {
+        return Err(Error::WithdrawalAmountAboveAllocation);
+    };
+    // Withdraw amount cannot be greater than the part of the allocation that is streamed so far
+    let locked_amount = StreamDataKey::get_locked_amount(env, shareholder, token_address);
+    if amount > allocation - locked_amount {
+        return Err(Error::WithdrawalAmountAboveReleasedAllocation);
+    };
+
+    if amount == allocation {
+        AllocationDataKey::remove_allocation(env, shareholder, token_address);
+        AllocationDataKey::remove_last_claim(env, shareholder, token_address);
+        StreamDataKey::remove_streams(env, shareholder, token_address);
+    } else {
+        AllocationDataKey::save_allocation(env, shareholder, token_address, allocation - amount);
+        // Withdrawing restarts the claim window
+        AllocationDataKey::save_last_claim(
+            env,
+            shareholder,
+            token_address,
+            env.ledger().sequence(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the account address of an ed25519 public key
+pub fn get_account_address(env: &Env, public_key: &BytesN<32>) -> Address {
+    // ScVal::Address(ScAddress::Account(PublicKey::Ed25519(public_key)))
+    let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+    xdr.extend_from_array(&public_key.to_array());
+    Address::from_xdr(env, &xdr).unwrap()
+}
+
+/// Builds the payload signed by the shareholder for a claim relayed by someone else
+///
+/// The payload is bound to the network and the contract so that a signature
+/// cannot be replayed on another splitter.
+pub fn get_claim_payload(
+    env: &Env,
+    token_address: &Address,
+    amount: i128,
+    nonce: u64,
+    expiry: u32,
+    max_relayer_fee: i128,
+) -> Bytes {
+    let mut payload = Bytes::from_slice(env, CLAIM_DOMAIN);
+    payload.append(&env.ledger().network_id().into());
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload.append(&token_address.clone().to_xdr(env));
+    payload.extend_from_array(&amount.to_be_bytes());
+    payload.extend_from_array(&nonce.to_be_bytes());
+    payload.extend_from_array(&expiry.to_be_bytes());
+    payload.extend_from_array(&max_relayer_fee.to_be_bytes());
+    payload
+}
+
+/// Adds the amount to the total allocation of the token, reserving it from the unused balance
+///
+/// Negative amounts release the reservation.
+pub fn reserve_amount(env: &Env, token_address: &Address, amount: i128) {
+    let total_allocation = AllocationDataKey::get_total_allocation(env, token_address).unwrap_or(0);
+    if total_allocation + amount == 0 {
+        AllocationDataKey::remove_total_allocation(env, token_address);
+    } else {
+        AllocationDataKey::save_total_allocation(env, token_address, total_allocation + amount);
+    }
+}
+
+/// Returns the leaf of a merkle distribution claim
+///
+/// Leaves are the double sha256 hash of the big endian index, the XDR of the recipient address
+/// and the big endian amount. Hashing twice keeps leaves apart from the internal nodes,
+/// so a pair of nodes cannot be claimed as a leaf.
+pub fn get_merkle_leaf(env: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+    let mut leaf = Bytes::from_array(env, &index.to_be_bytes());
+    leaf.append(&recipient.clone().to_xdr(env));
+    leaf.extend_from_array(&amount.to_be_bytes());
+    let hash: Bytes = env.crypto().sha256(&leaf).into();
+    env.crypto().sha256(&hash)
+}
+
+/// Checks if the proof links the leaf to the merkle root
+///
+/// Each pair of nodes is hashed in ascending order, so the proof does not need the positions.
+pub fn verify_merkle_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in proof.iter() {
+        let (first, second) = if node.to_array() <= sibling.to_array() {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair: Bytes = first.into();
+        pair.append(&second.into());
+        node = env.crypto().sha256(&pair);
+    }
+    node == *root
+}
+
+/// Sets the allocation of the shareholder to the corrected amount and logs the correction
+///
+/// The total allocation of the token follows the corrected amount.
+pub fn correct_allocation(
+    env: &Env,
+    kind: CorrectionKind,
+    shareholder: &Address,
+    token_address: &Address,
+    allocation: i128,
+    reason: String,
+) {
+    let previous = AllocationDataKey::get_allocation(env, shareholder, token_address).unwrap_or(0);
+
+    if allocation == 0 {
+        if previous > 0 {
+            AllocationDataKey::remove_allocation(env, shareholder, token_address);
+        };
+        AllocationDataKey::remove_last_claim(env, shareholder, token_address);
+        StreamDataKey::remove_streams(env, shareholder, token_address);
+    } else {
+        AllocationDataKey::save_allocation(env, shareholder, token_address, allocation);
+
+        // The streams cannot lock more than the corrected allocation
+        StreamDataKey::trim_streams(env, shareholder, token_address, allocation);
+
+        // Corrections keep the claim window running from the oldest credit
+        AllocationDataKey::start_claim_window(env, shareholder, token_address);
+    };
+
+    let correction = CorrectionDataKey {
+        kind,
+        shareholder: shareholder.clone(),
+        token: token_address.clone(),
+        previous,
+        allocation,
+        reason,
+        ledger: env.ledger().sequence(),
+    };
+    let index = CorrectionDataKey::add_correction(env, &correction);
+
+    events::correction(env, index, &correction);
+}
+
+/// Returns the token balance that is not allocated to the shareholders
+pub fn get_unused_balance(env: &Env, token_address: &Address) -> i128 {
+    let token_client = get_token_client(env, token_address);
+
+    // Get the available token balance
+    let balance = token_client.balance(&env.current_contract_address());
+
+    // Get the total allocation for the token
+    let total_allocation = AllocationDataKey::get_total_allocation(env, token_address).unwrap_or(0);
+
+    balance - total_allocation
+}
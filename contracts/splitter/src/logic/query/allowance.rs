@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{AllowanceDataKey, ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn query(env: Env, from: Address, spender: Address) -> Result<i128, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    Ok(AllowanceDataKey::get_allowance(&env, &from, &spender).amount)
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::get_shares,
+    storage::{ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn query(env: Env, id: Address) -> Result<i128, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    // The balance is the share in the default share table
+    Ok(get_shares(&env)
+        .iter()
+        .find(|share| share.shareholder == id)
+        .map(|share| share.share)
+        .unwrap_or(0))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareVersionDataKey},
+};
+
+pub fn query(env: Env) -> Result<u32, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ShareVersionDataKey::get_current_version(&env))
+}
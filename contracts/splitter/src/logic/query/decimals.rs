@@ -0,0 +1,18 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareTokenDataKey, SHARE_TOKEN_DECIMALS},
+};
+
+pub fn query(env: Env) -> Result<u32, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    if ShareTokenDataKey::get(&env).is_none() {
+        return Err(Error::ShareTokenNotEnabled);
+    };
+
+    Ok(SHARE_TOKEN_DECIMALS)
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{errors::Error, storage::ConfigDataKey};
+
+pub fn query(env: Env) -> Result<Option<Address>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ConfigDataKey::get_arbiter(&env))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{CallbackDataKey, ConfigDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(CallbackDataKey::is_enabled(&env, &shareholder))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{CascadeDataKey, ConfigDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(CascadeDataKey::is_enabled(&env, &shareholder))
+}
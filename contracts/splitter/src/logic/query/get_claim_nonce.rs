@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ClaimNonceDataKey, ConfigDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<u64, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ClaimNonceDataKey::get_nonce(&env, &shareholder))
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Bytes, Env};
+
+use crate::{errors::Error, logic::helpers::get_claim_payload, storage::ConfigDataKey};
+
+pub fn query(
+    env: Env,
+    token: Address,
+    amount: i128,
+    nonce: u64,
+    expiry: u32,
+    max_relayer_fee: i128,
+) -> Result<Bytes, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(get_claim_payload(
+        &env,
+        &token,
+        amount,
+        nonce,
+        expiry,
+        max_relayer_fee,
+    ))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ContributionDataKey},
+};
+
+pub fn query(env: Env, payer: Address, token: Address) -> Result<ContributionDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ContributionDataKey::get_contribution(&env, &payer, &token))
+}
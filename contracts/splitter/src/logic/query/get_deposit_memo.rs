@@ -0,0 +1,20 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ContributionDataKey},
+};
+
+pub fn query(
+    env: Env,
+    payer: Address,
+    token: Address,
+    index: u32,
+) -> Result<Option<String>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ContributionDataKey::get_deposit_memo(
+        &env, &payer, &token, index,
+    ))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, FeeDataKey},
+};
+
+pub fn query(env: Env) -> Result<Option<FeeDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(FeeDataKey::get(&env))
+}
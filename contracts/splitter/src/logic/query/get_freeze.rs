@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, FreezeDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<Option<FreezeDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    Ok(FreezeDataKey::get_freeze(&env, &shareholder))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, KeeperBountyDataKey},
+};
+
+pub fn query(env: Env) -> Result<Option<KeeperBountyDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(KeeperBountyDataKey::get(&env))
+}
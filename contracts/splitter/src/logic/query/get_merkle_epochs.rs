@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, MerkleRootDataKey},
+};
+
+pub fn query(env: Env, token: Address) -> Result<u32, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(MerkleRootDataKey::get_epoch_count(&env, &token))
+}
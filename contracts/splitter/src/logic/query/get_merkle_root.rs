@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, MerkleRootDataKey},
+};
+
+pub fn query(env: Env, token: Address, epoch: u32) -> Result<MerkleRootDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    match MerkleRootDataKey::get_root(&env, &token, epoch) {
+        Some(merkle_root) => Ok(merkle_root),
+        None => Err(Error::MerkleRootNotFound),
+    }
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, OperatorDataKey},
+};
+
+pub fn query(
+    env: Env,
+    shareholder: Address,
+    operator: Address,
+) -> Result<Option<OperatorDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(OperatorDataKey::get_operator(&env, &shareholder, &operator))
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, PayoutAddressDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<Address, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(PayoutAddressDataKey::get_payout_address(&env, &shareholder))
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, PayoutCap, PayoutCapDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address, token: Address) -> Result<Option<PayoutCap>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    match PayoutCapDataKey::get_cap(&env, &shareholder, &token) {
+        Some(cap) => Ok(Some(PayoutCap {
+            cap,
+            received: PayoutCapDataKey::get_received(&env, &shareholder, &token),
+        })),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{AllocationDataKey, ConfigDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address, token: Address) -> Result<Option<u32>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let config = ConfigDataKey::get(&env).unwrap();
+    if config.claim_window == 0 {
+        return Ok(None);
+    };
+
+    // The allocation can be reclaimed from the first ledger after its claim window
+    Ok(
+        AllocationDataKey::get_last_claim(&env, &shareholder, &token).map(|last_claim| {
+            last_claim
+                .saturating_add(config.claim_window)
+                .saturating_add(1)
+        }),
+    )
+}
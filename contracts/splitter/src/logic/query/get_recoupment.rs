@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, RecoupmentDataKey},
+};
+
+pub fn query(
+    env: Env,
+    shareholder: Address,
+    token: Address,
+) -> Result<Option<RecoupmentDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(RecoupmentDataKey::get_recoupment(
+        &env,
+        &shareholder,
+        &token,
+    ))
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{AllocationDataKey, ConfigDataKey, StreamDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address, token: Address) -> Result<i128, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    let allocation = AllocationDataKey::get_allocation(&env, &shareholder, &token).unwrap_or(0);
+    let locked_amount = StreamDataKey::get_locked_amount(&env, &shareholder, &token);
+    Ok(allocation - locked_amount)
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, SettlementTokensDataKey},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<Vec<Address>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(SettlementTokensDataKey::get_tokens(&env, &shareholder))
+}
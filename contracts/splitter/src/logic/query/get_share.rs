@@ -2,15 +2,27 @@ use soroban_sdk::{Address, Env};
 
 use crate::{
     errors::Error,
-    storage::{ConfigDataKey, ShareDataKey},
+    logic::helpers::{get_shares, get_token_shares},
+    storage::ConfigDataKey,
 };
 
-pub fn query(env: Env, shareholder: Address) -> Result<Option<i128>, Error> {
+pub fn query(
+    env: Env,
+    shareholder: Address,
+    token: Option<Address>,
+) -> Result<Option<i128>, Error> {
     if !ConfigDataKey::exists(&env) {
         return Err(Error::NotInitialized);
     };
-    match ShareDataKey::get_share(&env, &shareholder) {
-        Some(share) => Ok(Some(share.share)),
-        None => Ok(None),
-    }
+
+    // Look up the share in the share table of the token if one is given
+    let shares = match token {
+        Some(token) => get_token_shares(&env, &token),
+        None => get_shares(&env),
+    };
+
+    Ok(shares
+        .iter()
+        .find(|share| share.shareholder == shareholder)
+        .map(|share| share.share))
 }
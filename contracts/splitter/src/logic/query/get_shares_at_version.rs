@@ -0,0 +1,13 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareVersionDataKey},
+};
+
+pub fn query(env: Env, id: u32) -> Result<ShareVersionDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    ShareVersionDataKey::get_version(&env, id).ok_or(Error::ShareVersionNotFound)
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, MerkleRootDataKey},
+};
+
+pub fn query(env: Env, token: Address, epoch: u32, index: u32) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(MerkleRootDataKey::is_claimed(&env, &token, epoch, index))
+}
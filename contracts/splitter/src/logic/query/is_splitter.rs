@@ -0,0 +1,5 @@
+use soroban_sdk::Env;
+
+pub fn query(_env: Env) -> bool {
+    true
+}
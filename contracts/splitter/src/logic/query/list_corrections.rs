@@ -0,0 +1,24 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, CorrectionDataKey, MAX_PAGE_LIMIT},
+};
+
+pub fn query(env: Env, start: u32, limit: u32) -> Result<Vec<CorrectionDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let count = CorrectionDataKey::get_correction_count(&env);
+    let end = count.min(start.saturating_add(limit.min(MAX_PAGE_LIMIT)));
+
+    let mut corrections: Vec<CorrectionDataKey> = Vec::new(&env);
+    for index in start..end {
+        if let Some(correction) = CorrectionDataKey::get_correction(&env, index) {
+            corrections.push_back(correction);
+        };
+    }
+
+    Ok(corrections)
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ContributionDataKey, DepositDataKey, MAX_PAGE_LIMIT},
+};
+
+pub fn query(
+    env: Env,
+    payer: Address,
+    token: Address,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<DepositDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let count = ContributionDataKey::get_contribution(&env, &payer, &token).deposits;
+    let end = count.min(start.saturating_add(limit.min(MAX_PAGE_LIMIT)));
+
+    let mut deposits: Vec<DepositDataKey> = Vec::new(&env);
+    for index in start..end {
+        if let Some(deposit) = ContributionDataKey::get_deposit(&env, &payer, &token, index) {
+            deposits.push_back(deposit);
+        };
+    }
+
+    Ok(deposits)
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, FixedAmountDataKey},
+};
+
+pub fn query(env: Env, token: Address) -> Result<Vec<FixedAmountDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(FixedAmountDataKey::get_fixed_amounts(&env, &token))
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ContributionDataKey, MAX_PAGE_LIMIT},
+};
+
+pub fn query(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let count = ContributionDataKey::get_payer_count(&env);
+    let end = count.min(start.saturating_add(limit.min(MAX_PAGE_LIMIT)));
+
+    let mut payers: Vec<Address> = Vec::new(&env);
+    for index in start..end {
+        if let Some(payer) = ContributionDataKey::get_payer(&env, index) {
+            payers.push_back(payer);
+        };
+    }
+
+    Ok(payers)
+}
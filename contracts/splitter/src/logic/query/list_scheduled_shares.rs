@@ -0,0 +1,23 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ScheduledSharesDataKey},
+};
+
+pub fn query(env: Env) -> Result<Vec<ScheduledSharesDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Only the share tables that are not active yet are upcoming
+    let ledger = env.ledger().sequence();
+    let mut schedule: Vec<ScheduledSharesDataKey> = Vec::new(&env);
+    for scheduled in ScheduledSharesDataKey::get_schedule(&env).iter() {
+        if scheduled.activation_ledger > ledger {
+            schedule.push_back(scheduled);
+        };
+    }
+
+    Ok(schedule)
+}
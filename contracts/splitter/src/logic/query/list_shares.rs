@@ -1,21 +1,19 @@
-use soroban_sdk::{Env, Vec};
+use soroban_sdk::{Address, Env, Vec};
 
 use crate::{
     errors::Error,
+    logic::helpers::{get_shares, get_token_shares},
     storage::{ConfigDataKey, ShareDataKey},
 };
 
-pub fn query(env: Env) -> Result<Vec<ShareDataKey>, Error> {
+pub fn query(env: Env, token: Option<Address>) -> Result<Vec<ShareDataKey>, Error> {
     if !ConfigDataKey::exists(&env) {
         return Err(Error::NotInitialized);
     };
 
-    let mut shares: Vec<ShareDataKey> = Vec::new(&env);
-
-    for shareholder in ShareDataKey::get_shareholders(&env).iter() {
-        let share = ShareDataKey::get_share(&env, &shareholder).unwrap();
-        shares.push_back(share);
+    // Use the share table of the token if one is given
+    match token {
+        Some(token) => Ok(get_token_shares(&env, &token)),
+        None => Ok(get_shares(&env)),
     }
-
-    Ok(shares)
 }
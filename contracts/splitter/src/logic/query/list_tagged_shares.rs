@@ -0,0 +1,13 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareDataKey, TagDataKey},
+};
+
+pub fn query(env: Env, tag: Symbol) -> Result<Vec<ShareDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    TagDataKey::get_shares(&env, &tag).ok_or(Error::TagNotFound)
+}
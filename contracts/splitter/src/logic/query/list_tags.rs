@@ -0,0 +1,13 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, TagDataKey},
+};
+
+pub fn query(env: Env) -> Result<Vec<Symbol>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(TagDataKey::get_tags(&env))
+}
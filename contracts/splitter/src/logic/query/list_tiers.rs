@@ -0,0 +1,13 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, TierDataKey},
+};
+
+pub fn query(env: Env) -> Result<Vec<TierDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(TierDataKey::get_tiers(&env))
+}
@@ -1,9 +1,85 @@
+mod allowance;
+mod balance;
+mod current_share_version;
+mod decimals;
 mod get_allocation;
+mod get_arbiter;
+mod get_callback;
+mod get_cascade;
+mod get_claim_nonce;
+mod get_claim_payload;
 mod get_config;
+mod get_contributions;
+mod get_deposit_memo;
+mod get_fee;
+mod get_freeze;
+mod get_keeper_bounty;
+mod get_merkle_epochs;
+mod get_merkle_root;
+mod get_operator;
+mod get_payout_address;
+mod get_payout_cap;
+mod get_reclaim_ledger;
+mod get_recoupment;
+mod get_released_allocation;
+mod get_settlement_tokens;
 mod get_share;
+mod get_shares_at_version;
+mod get_tier_filled;
+mod is_merkle_claimed;
+mod is_splitter;
+mod list_corrections;
+mod list_deposits;
+mod list_fixed_amounts;
+mod list_payers;
+mod list_scheduled_shares;
 mod list_shares;
+mod list_tagged_shares;
+mod list_tags;
+mod list_tiers;
+mod name;
+mod preview_distribution;
+mod symbol;
 
+pub use allowance::query as allowance;
+pub use balance::query as balance;
+pub use current_share_version::query as current_share_version;
+pub use decimals::query as decimals;
 pub use get_allocation::query as get_allocation;
+pub use get_arbiter::query as get_arbiter;
+pub use get_callback::query as get_callback;
+pub use get_cascade::query as get_cascade;
+pub use get_claim_nonce::query as get_claim_nonce;
+pub use get_claim_payload::query as get_claim_payload;
 pub use get_config::query as get_config;
+pub use get_contributions::query as get_contributions;
+pub use get_deposit_memo::query as get_deposit_memo;
+pub use get_fee::query as get_fee;
+pub use get_freeze::query as get_freeze;
+pub use get_keeper_bounty::query as get_keeper_bounty;
+pub use get_merkle_epochs::query as get_merkle_epochs;
+pub use get_merkle_root::query as get_merkle_root;
+pub use get_operator::query as get_operator;
+pub use get_payout_address::query as get_payout_address;
+pub use get_payout_cap::query as get_payout_cap;
+pub use get_reclaim_ledger::query as get_reclaim_ledger;
+pub use get_recoupment::query as get_recoupment;
+pub use get_released_allocation::query as get_released_allocation;
+pub use get_settlement_tokens::query as get_settlement_tokens;
 pub use get_share::query as get_share;
+pub use get_shares_at_version::query as get_shares_at_version;
+pub use get_tier_filled::query as get_tier_filled;
+pub use is_merkle_claimed::query as is_merkle_claimed;
+pub use is_splitter::query as is_splitter;
+pub use list_corrections::query as list_corrections;
+pub use list_deposits::query as list_deposits;
+pub use list_fixed_amounts::query as list_fixed_amounts;
+pub use list_payers::query as list_payers;
+pub use list_scheduled_shares::query as list_scheduled_shares;
 pub use list_shares::query as list_shares;
+pub use list_tagged_shares::query as list_tagged_shares;
+pub use list_tags::query as list_tags;
+pub use list_tiers::query as list_tiers;
+pub use name::query as name;
+pub use preview_distribution::query as preview_distribution;
+pub use symbol::query as symbol;
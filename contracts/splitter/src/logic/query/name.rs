@@ -0,0 +1,17 @@
+use soroban_sdk::{Env, String};
+
+use crate::{
+    errors::Error,
+    storage::{ConfigDataKey, ShareTokenDataKey},
+};
+
+pub fn query(env: Env) -> Result<String, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    match ShareTokenDataKey::get(&env) {
+        Some(share_token) => Ok(share_token.name),
+        None => Err(Error::ShareTokenNotEnabled),
+    }
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{calculate_distribution, get_unused_balance, promote_scheduled_shares},
+    storage::{ConfigDataKey, Distribution, KeeperBountyDataKey},
+};
+
+pub fn query(
+    env: Env,
+    token_address: Address,
+    keeper: Option<Address>,
+    tag: Option<Symbol>,
+) -> Result<Distribution, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Keeper bounty is only paid if a keeper is executing the distribution
+    let keeper_bounty = match keeper {
+        Some(_) => KeeperBountyDataKey::get(&env),
+        None => None,
+    };
+
+    // Activate the scheduled share tables that are due
+    promote_scheduled_shares(&env);
+
+    let unused_balance = get_unused_balance(&env, &token_address);
+
+    calculate_distribution(&env, &token_address, unused_balance, &keeper_bounty, &tag)
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address, Env, IntoVal, Val, Vec};
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec};
 
 use crate::errors::Error;
 
@@ -10,6 +11,21 @@ const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
 const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
+/// The maximum number of nested splitters a distribution cascades through
+pub const MAX_CASCADE_DEPTH: u32 = 3;
+
+/// The maximum number of entries returned by the paginated queries
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// The number of decimals of the share token, balances are shown as percentages
+pub const SHARE_TOKEN_DECIMALS: u32 = 2;
+
+/// The maximum number of ledgers a shareholder's allocations can stay frozen for
+pub const MAX_FREEZE_DURATION: u32 = 90 * DAY_IN_LEDGERS;
+
+/// Maximum number of tokens a shareholder can have settled before share changes
+pub const MAX_SETTLEMENT_TOKENS: u32 = 10;
+
 fn bump_instance(e: &Env) {
     e.storage()
         .instance()
@@ -87,18 +103,359 @@ impl ShareDataKey {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ShareVersionDataKey {
+    pub id: u32,
+    /// Ledger sequence at which the share table took effect
+    pub ledger: u32,
+    /// Share table that was changed
+    pub table: ShareTable,
+    /// Shareholders of the table, empty if the table was removed
+    pub shares: Vec<ShareDataKey>,
+}
+impl ShareVersionDataKey {
+    /// Saves the share table as a new version and returns its id
+    ///
+    /// Versions of all share tables share the same sequence of ids.
+    pub fn add_version(
+        e: &Env,
+        table: &ShareTable,
+        shares: &Vec<ShareDataKey>,
+        ledger: u32,
+    ) -> u32 {
+        let id = Self::get_current_version(e) + 1;
+
+        let key = DataKey::ShareVersion(id);
+        e.storage().persistent().set(
+            &key,
+            &ShareVersionDataKey {
+                id,
+                ledger,
+                table: table.clone(),
+                shares: shares.clone(),
+            },
+        );
+        bump_persistent(e, &key);
+
+        let key = DataKey::TableVersion(table.clone());
+        e.storage().persistent().set(&key, &id);
+        bump_persistent(e, &key);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::CurrentShareVersion, &id);
+        bump_instance(e);
+
+        id
+    }
+
+    /// Returns the id of the latest share table version
+    pub fn get_current_version(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CurrentShareVersion)
+            .unwrap_or(0)
+    }
+
+    /// Returns the id of the latest version of the share table
+    pub fn get_table_version(e: &Env, table: &ShareTable) -> u32 {
+        let key = DataKey::TableVersion(table.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(id) => {
+                bump_persistent(e, &key);
+                id
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns the share table version
+    pub fn get_version(e: &Env, id: u32) -> Option<ShareVersionDataKey> {
+        let key = DataKey::ShareVersion(id);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(version) => {
+                bump_persistent(e, &key);
+                Some(version)
+            }
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ScheduledSharesDataKey {
+    /// Ledger sequence at which the share table becomes active
+    pub activation_ledger: u32,
+    pub shares: Vec<ShareDataKey>,
+}
+impl ScheduledSharesDataKey {
+    /// Saves the list of scheduled share tables ordered by their activation ledger
+    pub fn save_schedule(e: &Env, schedule: &Vec<ScheduledSharesDataKey>) {
+        let key = DataKey::ScheduledShares;
+        e.storage().persistent().set(&key, schedule);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the list of scheduled share tables ordered by their activation ledger
+    pub fn get_schedule(e: &Env) -> Vec<ScheduledSharesDataKey> {
+        let key = DataKey::ScheduledShares;
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<ScheduledSharesDataKey>>(&key);
+        match res {
+            Some(schedule) => {
+                bump_persistent(e, &key);
+                schedule
+            }
+            None => Vec::new(e),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagDataKey {}
+impl TagDataKey {
+    /// Saves the share table for the tag, adding the tag to the list of tags if it is new
+    pub fn save_shares(e: &Env, tag: &Symbol, shares: &Vec<ShareDataKey>) {
+        let key = DataKey::TaggedShares(tag.clone());
+        e.storage().persistent().set(&key, shares);
+        bump_persistent(e, &key);
+
+        let mut tags = Self::get_tags(e);
+        if !tags.contains(tag) {
+            tags.push_back(tag.clone());
+            Self::save_tags(e, &tags);
+        };
+    }
+
+    /// Returns the share table for the tag
+    pub fn get_shares(e: &Env, tag: &Symbol) -> Option<Vec<ShareDataKey>> {
+        let key = DataKey::TaggedShares(tag.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<ShareDataKey>>(&key);
+        match res {
+            Some(shares) => {
+                bump_persistent(e, &key);
+                Some(shares)
+            }
+            None => None,
+        }
+    }
+
+    /// Removes the share table for the tag
+    pub fn remove_shares(e: &Env, tag: &Symbol) {
+        let key = DataKey::TaggedShares(tag.clone());
+        e.storage().persistent().remove(&key);
+
+        let mut tags = Self::get_tags(e);
+        if let Some(index) = tags.first_index_of(tag) {
+            tags.remove(index);
+            Self::save_tags(e, &tags);
+        };
+    }
+
+    fn save_tags(e: &Env, tags: &Vec<Symbol>) {
+        let key = DataKey::Tags;
+        e.storage().persistent().set(&key, tags);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the list of tags with a share table
+    pub fn get_tags(e: &Env) -> Vec<Symbol> {
+        let key = DataKey::Tags;
+        let res = e.storage().persistent().get::<DataKey, Vec<Symbol>>(&key);
+        match res {
+            Some(tags) => {
+                bump_persistent(e, &key);
+                tags
+            }
+            None => Vec::new(e),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSharesDataKey {}
+impl TokenSharesDataKey {
+    /// Saves the share table overriding the default share table for the token,
+    /// adding the token to the list of overridden tokens if it is new
+    pub fn save_shares(e: &Env, token: &Address, shares: &Vec<ShareDataKey>) {
+        let key = DataKey::TokenShares(token.clone());
+        e.storage().persistent().set(&key, shares);
+        bump_persistent(e, &key);
+
+        let mut tokens = Self::get_tokens(e);
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            Self::save_tokens(e, &tokens);
+        };
+    }
+
+    /// Returns the share table overriding the default share table for the token
+    pub fn get_shares(e: &Env, token: &Address) -> Option<Vec<ShareDataKey>> {
+        let key = DataKey::TokenShares(token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<ShareDataKey>>(&key);
+        match res {
+            Some(shares) => {
+                bump_persistent(e, &key);
+                Some(shares)
+            }
+            None => None,
+        }
+    }
+
+    /// Removes the share table override for the token
+    pub fn remove_shares(e: &Env, token: &Address) {
+        let key = DataKey::TokenShares(token.clone());
+        e.storage().persistent().remove(&key);
+
+        let mut tokens = Self::get_tokens(e);
+        if let Some(index) = tokens.first_index_of(token) {
+            tokens.remove(index);
+            Self::save_tokens(e, &tokens);
+        };
+    }
+
+    fn save_tokens(e: &Env, tokens: &Vec<Address>) {
+        let key = DataKey::TokenSharesTokens;
+        e.storage().persistent().set(&key, tokens);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the list of tokens with a share table override
+    pub fn get_tokens(e: &Env) -> Vec<Address> {
+        let key = DataKey::TokenSharesTokens;
+        let res = e.storage().persistent().get::<DataKey, Vec<Address>>(&key);
+        match res {
+            Some(tokens) => {
+                bump_persistent(e, &key);
+                tokens
+            }
+            None => Vec::new(e),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ShareTokenDataKey {
+    /// Name of the share token
+    pub name: String,
+    /// Symbol of the share token
+    pub symbol: String,
+}
+impl ShareTokenDataKey {
+    /// Saves the metadata of the share token, enabling the token interface
+    pub fn save(e: &Env, share_token: &ShareTokenDataKey) {
+        bump_instance(e);
+        let key = DataKey::ShareToken;
+        e.storage().instance().set(&key, share_token);
+    }
+
+    /// Returns the metadata of the share token if the token interface is enabled
+    pub fn get(e: &Env) -> Option<ShareTokenDataKey> {
+        bump_instance(e);
+        let key = DataKey::ShareToken;
+        e.storage().instance().get(&key)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AllowanceDataKey {
+    /// Share in basis points the spender can transfer
+    pub amount: i128,
+    /// Ledger sequence after which the allowance is no longer valid
+    pub expiration_ledger: u32,
+}
+impl AllowanceDataKey {
+    /// Saves the share the spender can transfer on behalf of the owner
+    pub fn save_allowance(
+        e: &Env,
+        from: &Address,
+        spender: &Address,
+        allowance: &AllowanceDataKey,
+    ) {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        if allowance.amount > 0 {
+            e.storage().persistent().set(&key, allowance);
+            bump_persistent(e, &key);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns the share the spender can transfer on behalf of the owner
+    ///
+    /// Expired allowances are returned as 0.
+    pub fn get_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceDataKey {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, AllowanceDataKey>(&key);
+        match res {
+            Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => {
+                bump_persistent(e, &key);
+                allowance
+            }
+            _ => AllowanceDataKey {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+}
+
+/// What happens to the allocations reclaimed after their claim window
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ReclaimMode {
+    /// The allocation is returned to the unused balance of the contract
+    UnusedBalance,
+    /// The allocation is split between the other shareholders of the default share table
+    Redistribute,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct ConfigDataKey {
     pub admin: Address,
     pub mutable: bool,
+    /// Number of ledgers over which each distribution is streamed to the shareholders.
+    /// Distributions are allocated at once if the value is 0.
+    pub stream_duration: u32,
+    /// Allows the shareholders to transfer their shares even if the contract is locked
+    pub shares_transferable: bool,
+    /// Number of ledgers an allocation can stay unclaimed before it can be reclaimed.
+    /// Allocations never expire if the value is 0.
+    pub claim_window: u32,
+    /// What happens to the reclaimed allocations
+    pub reclaim_mode: ReclaimMode,
 }
 impl ConfigDataKey {
     /// Initializes the config with the given admin address and mutable flag
     pub fn init(e: &Env, admin: Address, mutable: bool) {
         bump_instance(e);
         let key = DataKey::Config;
-        let config = ConfigDataKey { admin, mutable };
+        let config = ConfigDataKey {
+            admin,
+            mutable,
+            stream_duration: 0,
+            shares_transferable: false,
+            claim_window: 0,
+            reclaim_mode: ReclaimMode::UnusedBalance,
+        };
         e.storage().instance().set(&key, &config);
     }
 
@@ -123,6 +480,40 @@ impl ConfigDataKey {
         }
     }
 
+    /// Updates the number of ledgers the distributions are streamed over
+    pub fn set_stream_duration(e: &Env, stream_duration: u32) {
+        bump_instance(e);
+        let key = DataKey::Config;
+        let config: Option<ConfigDataKey> = e.storage().instance().get(&key);
+        if let Some(mut config) = config {
+            config.stream_duration = stream_duration;
+            e.storage().instance().set(&key, &config);
+        }
+    }
+
+    /// Updates whether the shareholders can transfer their shares on a locked contract
+    pub fn set_shares_transferable(e: &Env, shares_transferable: bool) {
+        bump_instance(e);
+        let key = DataKey::Config;
+        let config: Option<ConfigDataKey> = e.storage().instance().get(&key);
+        if let Some(mut config) = config {
+            config.shares_transferable = shares_transferable;
+            e.storage().instance().set(&key, &config);
+        }
+    }
+
+    /// Updates the claim window of the allocations and what happens once they expire
+    pub fn set_claim_window(e: &Env, claim_window: u32, reclaim_mode: ReclaimMode) {
+        bump_instance(e);
+        let key = DataKey::Config;
+        let config: Option<ConfigDataKey> = e.storage().instance().get(&key);
+        if let Some(mut config) = config {
+            config.claim_window = claim_window;
+            config.reclaim_mode = reclaim_mode;
+            e.storage().instance().set(&key, &config);
+        }
+    }
+
     /// Returns true if ConfigDataKey exists in the storage
     pub fn exists(e: &Env) -> bool {
         bump_instance(e);
@@ -139,29 +530,124 @@ impl ConfigDataKey {
         Ok(())
     }
 
-    /// Returns true if the contract is mutable
+    /// Saves the arbiter that can freeze the allocations of a shareholder during disputes
+    pub fn save_arbiter(e: &Env, arbiter: &Address) {
+        bump_instance(e);
+        let key = DataKey::Arbiter;
+        e.storage().instance().set(&key, arbiter);
+    }
+
+    /// Returns the arbiter that can freeze the allocations of a shareholder during disputes
+    pub fn get_arbiter(e: &Env) -> Option<Address> {
+        bump_instance(e);
+        let key = DataKey::Arbiter;
+        e.storage().instance().get(&key)
+    }
+
+    /// Requires the authorization of the arbiter
+    ///
+    /// Fails if the contract has no arbiter.
+    pub fn require_arbiter(e: &Env) -> Result<(), Error> {
+        match Self::get_arbiter(e) {
+            Some(arbiter) => {
+                arbiter.require_auth();
+                Ok(())
+            }
+            None => Err(Error::Unauthorized),
+        }
+    }
+
+    /// Returns true if the contract is locked
     // TODO: Maybe return an error if ConfigDataKey doesn't exist
     pub fn is_contract_locked(e: &Env) -> bool {
         bump_instance(e);
         let key = DataKey::Config;
         let config: Option<ConfigDataKey> = e.storage().instance().get(&key);
         match config {
-            Some(config) => config.mutable,
+            Some(config) => !config.mutable,
             None => false,
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FeeDataKey {
+    /// Recipient of the fee that is carved out of every distribution
+    pub recipient: Address,
+    /// Fee in basis points of the distributed amount
+    pub fee: i128,
+}
+impl FeeDataKey {
+    /// Saves the fee carved out of every distribution
+    pub fn save(e: &Env, fee: &FeeDataKey) {
+        bump_instance(e);
+        let key = DataKey::Fee;
+        e.storage().instance().set(&key, fee);
+    }
+
+    /// Returns the fee carved out of every distribution
+    pub fn get(e: &Env) -> Option<FeeDataKey> {
+        bump_instance(e);
+        let key = DataKey::Fee;
+        e.storage().instance().get(&key)
+    }
+
+    /// Removes the fee, disabling the fee on distributions
+    pub fn remove(e: &Env) {
+        bump_instance(e);
+        let key = DataKey::Fee;
+        e.storage().instance().remove(&key);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct KeeperBountyDataKey {
+    /// Fee paid to the keeper in basis points of the distributed amount
+    pub fee: i128,
+    /// Maximum fee paid to the keeper for a single distribution
+    pub cap: Option<i128>,
+    /// Minimum number of ledgers between two distributions of the same token
+    pub min_interval: u32,
+}
+impl KeeperBountyDataKey {
+    /// Saves the bounty paid to the keepers calling the distribution
+    pub fn save(e: &Env, keeper_bounty: &KeeperBountyDataKey) {
+        bump_instance(e);
+        let key = DataKey::KeeperBounty;
+        e.storage().instance().set(&key, keeper_bounty);
+    }
+
+    /// Returns the bounty paid to the keepers calling the distribution
+    pub fn get(e: &Env) -> Option<KeeperBountyDataKey> {
+        bump_instance(e);
+        let key = DataKey::KeeperBounty;
+        e.storage().instance().get(&key)
+    }
+
+    /// Removes the keeper bounty, disabling the keeper distributions
+    pub fn remove(e: &Env) {
+        bump_instance(e);
+        let key = DataKey::KeeperBounty;
+        e.storage().instance().remove(&key);
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct AllocationDataKey {}
 impl AllocationDataKey {
     // ========== User Allocation ==========
 
     /// Initializes the share for the shareholder
+    ///
+    /// The total allocation for the token is updated with the difference
+    /// between the new and the previous allocation.
     pub fn save_allocation(e: &Env, shareholder: &Address, token: &Address, allocation: i128) {
+        let previous_allocation = Self::get_allocation(e, shareholder, token).unwrap_or(0);
         match Self::get_total_allocation(e, token) {
             Some(total_allocation) => {
-                let new_total_allocation = total_allocation + allocation;
+                let new_total_allocation = total_allocation + allocation - previous_allocation;
                 Self::save_total_allocation(e, token, new_total_allocation);
             }
             None => {
@@ -205,6 +691,47 @@ impl AllocationDataKey {
         }
     }
 
+    // ========== Claim Window ==========
+
+    /// Restarts the claim window of the allocation from the current ledger
+    pub fn restart_claim_window(e: &Env, shareholder: &Address, token: &Address) {
+        Self::save_last_claim(e, shareholder, token, e.ledger().sequence());
+    }
+
+    /// Starts the claim window of the allocation from the current ledger unless it is running
+    ///
+    /// The window keeps running from the last withdrawal or the oldest credit not withdrawn yet.
+    pub fn start_claim_window(e: &Env, shareholder: &Address, token: &Address) {
+        if Self::get_last_claim(e, shareholder, token).is_none() {
+            Self::restart_claim_window(e, shareholder, token);
+        };
+    }
+
+    /// Saves the ledger sequence the claim window of the allocation starts from
+    pub fn save_last_claim(e: &Env, shareholder: &Address, token: &Address, ledger: u32) {
+        let key = DataKey::LastClaim(shareholder.clone(), token.clone());
+        e.storage().persistent().set(&key, &ledger);
+        bump_persistent(e, &key);
+    }
+
+    pub fn remove_last_claim(e: &Env, shareholder: &Address, token: &Address) {
+        let key = DataKey::LastClaim(shareholder.clone(), token.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the ledger sequence the claim window of the allocation starts from
+    pub fn get_last_claim(e: &Env, shareholder: &Address, token: &Address) -> Option<u32> {
+        let key = DataKey::LastClaim(shareholder.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(ledger) => {
+                bump_persistent(e, &key);
+                Some(ledger)
+            }
+            None => None,
+        }
+    }
+
     // ========== Total Allocation ==========
 
     pub fn save_total_allocation(e: &Env, token: &Address, total_allocation: i128) {
@@ -231,25 +758,1174 @@ impl AllocationDataKey {
     }
 }
 
-#[derive(Clone)]
-#[contracttype]
-pub enum DataKey {
-    Config,
-    // Storage keys for the shareholder and share data
-    //
-    /// Data key for keeping all of the shareholders in the contract
-    Shareholders,
-    /// Data key for keeping the share of a shareholder.
-    /// User addresses are mapped to their shares
-    Share(Address),
-    // Storage keys for the allocations
-    //
-    /// Data key for keeping the total allocation amount for a token.
-    /// Token addresses are mapped to their total allocation amount.
-    TotalAllocation(Address),
-    /// Data key for keeping the allocation amount for a shareholder.
-    /// User addresses with token addresses are mapped to their allocation amount.
-    ///
-    /// (UserAddr, TokenAddr) -> Allocation
-    Allocation(Address, Address),
+#[derive(Clone, Debug, PartialEq)]
+pub struct CascadeDataKey {}
+impl CascadeDataKey {
+    /// Enables or disables cascading the distributions to the shareholder
+    pub fn save_enabled(e: &Env, shareholder: &Address, enabled: bool) {
+        let key = DataKey::Cascade(shareholder.clone());
+        if enabled {
+            e.storage().persistent().set(&key, &true);
+            bump_persistent(e, &key);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns if the distributions are cascaded to the shareholder
+    pub fn is_enabled(e: &Env, shareholder: &Address) -> bool {
+        let key = DataKey::Cascade(shareholder.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(enabled) => {
+                bump_persistent(e, &key);
+                enabled
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallbackDataKey {}
+impl CallbackDataKey {
+    /// Enables or disables the allocation callback for the shareholder
+    pub fn save_enabled(e: &Env, shareholder: &Address, enabled: bool) {
+        let key = DataKey::Callback(shareholder.clone());
+        if enabled {
+            e.storage().persistent().set(&key, &true);
+            bump_persistent(e, &key);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns if the allocation callback is enabled for the shareholder
+    pub fn is_enabled(e: &Env, shareholder: &Address) -> bool {
+        let key = DataKey::Callback(shareholder.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(enabled) => {
+                bump_persistent(e, &key);
+                enabled
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutAddressDataKey {}
+impl PayoutAddressDataKey {
+    /// Saves the address receiving the payouts of the shareholder
+    pub fn save_payout_address(e: &Env, shareholder: &Address, payout_address: &Address) {
+        let key = DataKey::PayoutAddress(shareholder.clone());
+        e.storage().persistent().set(&key, payout_address);
+        bump_persistent(e, &key);
+    }
+
+    /// Removes the payout address, sending the payouts to the shareholder again
+    pub fn remove_payout_address(e: &Env, shareholder: &Address) {
+        let key = DataKey::PayoutAddress(shareholder.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the address receiving the payouts of the shareholder
+    ///
+    /// Defaults to the shareholder if no payout address is set.
+    pub fn get_payout_address(e: &Env, shareholder: &Address) -> Address {
+        let key = DataKey::PayoutAddress(shareholder.clone());
+        let res = e.storage().persistent().get::<DataKey, Address>(&key);
+        match res {
+            Some(payout_address) => {
+                bump_persistent(e, &key);
+                payout_address
+            }
+            None => shareholder.clone(),
+        }
+    }
+}
+
+pub struct SettlementTokensDataKey {}
+impl SettlementTokensDataKey {
+    /// Saves the tokens settled before the share of the shareholder changes
+    pub fn save_tokens(e: &Env, shareholder: &Address, tokens: &Vec<Address>) {
+        let key = DataKey::SettlementTokens(shareholder.clone());
+        e.storage().persistent().set(&key, tokens);
+        bump_persistent(e, &key);
+    }
+
+    /// Removes the settlement tokens of the shareholder
+    pub fn remove_tokens(e: &Env, shareholder: &Address) {
+        let key = DataKey::SettlementTokens(shareholder.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the tokens settled before the share of the shareholder changes
+    pub fn get_tokens(e: &Env, shareholder: &Address) -> Vec<Address> {
+        let key = DataKey::SettlementTokens(shareholder.clone());
+        let res = e.storage().persistent().get::<DataKey, Vec<Address>>(&key);
+        match res {
+            Some(tokens) => {
+                bump_persistent(e, &key);
+                tokens
+            }
+            None => Vec::new(e),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FreezeDataKey {
+    /// Ledger sequence the freeze started at
+    pub frozen_at: u32,
+    /// Ledger sequence the freeze expires at
+    pub frozen_until: u32,
+}
+impl FreezeDataKey {
+    /// Saves the freeze of the shareholder's allocations
+    pub fn save_freeze(e: &Env, shareholder: &Address, freeze: &FreezeDataKey) {
+        let key = DataKey::Freeze(shareholder.clone());
+        e.storage().persistent().set(&key, freeze);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the last freeze of the shareholder's allocations, even if it is over
+    pub fn get_last_freeze(e: &Env, shareholder: &Address) -> Option<FreezeDataKey> {
+        let key = DataKey::Freeze(shareholder.clone());
+        let res = e.storage().persistent().get::<DataKey, FreezeDataKey>(&key);
+        match res {
+            Some(freeze) => {
+                bump_persistent(e, &key);
+                Some(freeze)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the active freeze of the shareholder's allocations
+    ///
+    /// Expired freezes are ignored.
+    pub fn get_freeze(e: &Env, shareholder: &Address) -> Option<FreezeDataKey> {
+        Self::get_last_freeze(e, shareholder)
+            .filter(|freeze| freeze.frozen_until > e.ledger().sequence())
+    }
+
+    /// Returns true if the shareholder's allocations are frozen
+    pub fn is_frozen(e: &Env, shareholder: &Address) -> bool {
+        Self::get_freeze(e, shareholder).is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimNonceDataKey {}
+impl ClaimNonceDataKey {
+    /// Returns the nonce the next signed claim of the shareholder must use
+    pub fn get_nonce(e: &Env, shareholder: &Address) -> u64 {
+        let key = DataKey::ClaimNonce(shareholder.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(nonce) => {
+                bump_persistent(e, &key);
+                nonce
+            }
+            None => 0,
+        }
+    }
+
+    /// Consumes the current nonce of the shareholder
+    pub fn increment_nonce(e: &Env, shareholder: &Address) {
+        let nonce = Self::get_nonce(e, shareholder);
+        let key = DataKey::ClaimNonce(shareholder.clone());
+        e.storage().persistent().set(&key, &(nonce + 1));
+        bump_persistent(e, &key);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OperatorDataKey {
+    /// Ledger sequence after which the operator can no longer withdraw
+    pub expiration_ledger: u32,
+    /// Remaining amount the operator can withdraw per token, tokens without a limit are unlimited
+    pub limits: Map<Address, i128>,
+}
+impl OperatorDataKey {
+    /// Saves the approval of the operator withdrawing on behalf of the shareholder
+    pub fn save_operator(e: &Env, shareholder: &Address, operator: &Address, approval: &Self) {
+        let key = DataKey::Operator(shareholder.clone(), operator.clone());
+        e.storage().persistent().set(&key, approval);
+        bump_persistent(e, &key);
+    }
+
+    /// Removes the approval of the operator
+    pub fn remove_operator(e: &Env, shareholder: &Address, operator: &Address) {
+        let key = DataKey::Operator(shareholder.clone(), operator.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the approval of the operator if it has not expired
+    pub fn get_operator(e: &Env, shareholder: &Address, operator: &Address) -> Option<Self> {
+        let key = DataKey::Operator(shareholder.clone(), operator.clone());
+        let res = e.storage().persistent().get::<DataKey, Self>(&key);
+        match res {
+            Some(approval) if approval.expiration_ledger >= e.ledger().sequence() => {
+                bump_persistent(e, &key);
+                Some(approval)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Payout cap of a shareholder for a token with the amount received so far
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutCap {
+    /// The maximum amount of the token the shareholder can ever receive
+    pub cap: i128,
+    /// The amount of the token the shareholder has received so far
+    pub received: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutCapDataKey {}
+impl PayoutCapDataKey {
+    /// Saves the payout cap of the shareholder for the token
+    pub fn save_cap(e: &Env, shareholder: &Address, token: &Address, cap: i128) {
+        let key = DataKey::PayoutCap(shareholder.clone(), token.clone());
+        e.storage().persistent().set(&key, &cap);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the payout cap of the shareholder for the token
+    pub fn get_cap(e: &Env, shareholder: &Address, token: &Address) -> Option<i128> {
+        let key = DataKey::PayoutCap(shareholder.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(cap) => {
+                bump_persistent(e, &key);
+                Some(cap)
+            }
+            None => None,
+        }
+    }
+
+    /// Removes the payout cap of the shareholder for the token
+    pub fn remove_cap(e: &Env, shareholder: &Address, token: &Address) {
+        let key = DataKey::PayoutCap(shareholder.clone(), token.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Saves the lifetime amount of the token received by the shareholder
+    pub fn save_received(e: &Env, shareholder: &Address, token: &Address, received: i128) {
+        let key = DataKey::Received(shareholder.clone(), token.clone());
+        e.storage().persistent().set(&key, &received);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the lifetime amount of the token received by the shareholder
+    pub fn get_received(e: &Env, shareholder: &Address, token: &Address) -> i128 {
+        let key = DataKey::Received(shareholder.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(received) => {
+                bump_persistent(e, &key);
+                received
+            }
+            None => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RecoupmentDataKey {
+    /// The recipient of the diverted allocations
+    pub recipient: Address,
+    /// The outstanding amount of the advance
+    pub balance: i128,
+}
+impl RecoupmentDataKey {
+    /// Records an advance paid to the shareholder
+    pub fn add_advance(
+        e: &Env,
+        shareholder: &Address,
+        token: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        let balance = match Self::get_recoupment(e, shareholder, token) {
+            Some(recoupment) => recoupment.balance + amount,
+            None => amount,
+        };
+        Self::save_recoupment(e, shareholder, token, recipient, balance);
+    }
+
+    /// Reduces the outstanding advance of the shareholder, removing it once it is repaid
+    pub fn recoup(e: &Env, shareholder: &Address, token: &Address, amount: i128) {
+        if let Some(recoupment) = Self::get_recoupment(e, shareholder, token) {
+            let balance = recoupment.balance - amount;
+            if balance <= 0 {
+                Self::remove_recoupment(e, shareholder, token);
+            } else {
+                Self::save_recoupment(e, shareholder, token, &recoupment.recipient, balance);
+            }
+        };
+    }
+
+    fn save_recoupment(
+        e: &Env,
+        shareholder: &Address,
+        token: &Address,
+        recipient: &Address,
+        balance: i128,
+    ) {
+        let key = DataKey::Recoupment(shareholder.clone(), token.clone());
+        e.storage().persistent().set(
+            &key,
+            &RecoupmentDataKey {
+                recipient: recipient.clone(),
+                balance,
+            },
+        );
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the outstanding advance of the shareholder for the token
+    pub fn get_recoupment(
+        e: &Env,
+        shareholder: &Address,
+        token: &Address,
+    ) -> Option<RecoupmentDataKey> {
+        let key = DataKey::Recoupment(shareholder.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(recoupment) => {
+                bump_persistent(e, &key);
+                Some(recoupment)
+            }
+            None => None,
+        }
+    }
+
+    fn remove_recoupment(e: &Env, shareholder: &Address, token: &Address) {
+        let key = DataKey::Recoupment(shareholder.clone(), token.clone());
+        e.storage().persistent().remove(&key);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TierDataKey {
+    /// The shareholders of the tier with their shares
+    pub shares: Vec<ShareDataKey>,
+    /// The cumulative amount of each token that is distributed to the tier,
+    /// tokens without a cap are not distributed to the tier
+    pub caps: Map<Address, i128>,
+}
+impl TierDataKey {
+    /// Saves the ordered list of tiers
+    pub fn save_tiers(e: &Env, tiers: &Vec<TierDataKey>) {
+        let key = DataKey::Tiers;
+        e.storage().persistent().set(&key, tiers);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the ordered list of tiers
+    pub fn get_tiers(e: &Env) -> Vec<TierDataKey> {
+        let key = DataKey::Tiers;
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<TierDataKey>>(&key);
+        match res {
+            Some(tiers) => {
+                bump_persistent(e, &key);
+                tiers
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    /// Saves the amount of the token that is distributed to the tier
+    pub fn save_filled(e: &Env, index: u32, token: &Address, filled: i128) {
+        let key = DataKey::TierFilled(index, token.clone());
+        e.storage().persistent().set(&key, &filled);
+        bump_persistent(e, &key);
+    }
+
+    /// Removes the amount of the token that is distributed to the tier
+    pub fn remove_filled(e: &Env, index: u32, token: &Address) {
+        let key = DataKey::TierFilled(index, token.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the amount of the token that is distributed to the tier
+    pub fn get_filled(e: &Env, index: u32, token: &Address) -> i128 {
+        let key = DataKey::TierFilled(index, token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(filled) => {
+                bump_persistent(e, &key);
+                filled
+            }
+            None => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FixedAmountDataKey {
+    /// The recipient of the fixed amount
+    pub recipient: Address,
+    /// The amount of the token paid to the recipient
+    pub amount: i128,
+    /// The number of ledgers between payments, 0 pays on every distribution
+    pub period: u32,
+}
+impl FixedAmountDataKey {
+    /// Saves the list of fixed amounts paid for the token
+    pub fn save_fixed_amounts(e: &Env, token: &Address, fixed_amounts: &Vec<FixedAmountDataKey>) {
+        let key = DataKey::FixedAmounts(token.clone());
+        e.storage().persistent().set(&key, fixed_amounts);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the list of fixed amounts paid for the token
+    pub fn get_fixed_amounts(e: &Env, token: &Address) -> Vec<FixedAmountDataKey> {
+        let key = DataKey::FixedAmounts(token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<FixedAmountDataKey>>(&key);
+        match res {
+            Some(fixed_amounts) => {
+                bump_persistent(e, &key);
+                fixed_amounts
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    /// Saves the ledger sequence of the last fixed amount payment to the recipient
+    pub fn save_last_paid(e: &Env, recipient: &Address, token: &Address, ledger: u32) {
+        let key = DataKey::FixedAmountPaid(recipient.clone(), token.clone());
+        e.storage().persistent().set(&key, &ledger);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the ledger sequence of the last fixed amount payment to the recipient
+    pub fn get_last_paid(e: &Env, recipient: &Address, token: &Address) -> Option<u32> {
+        let key = DataKey::FixedAmountPaid(recipient.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(ledger) => {
+                bump_persistent(e, &key);
+                Some(ledger)
+            }
+            None => None,
+        }
+    }
+
+    /// Records the payment of the fixed amount at the given ledger
+    ///
+    /// Periodic payments stay on their schedule, the last payment is moved to the latest
+    /// period boundary instead of the ledger so that the payments do not drift later.
+    pub fn record_payment(&self, e: &Env, token: &Address, ledger: u32) {
+        let paid = match Self::get_last_paid(e, &self.recipient, token) {
+            Some(last_paid) if self.period > 0 && ledger >= last_paid => {
+                last_paid + (ledger - last_paid) / self.period * self.period
+            }
+            _ => ledger,
+        };
+        Self::save_last_paid(e, &self.recipient, token, paid);
+    }
+
+    /// Returns if the fixed amount is due at the given ledger
+    pub fn is_due(&self, e: &Env, token: &Address, ledger: u32) -> bool {
+        if self.period == 0 {
+            return true;
+        };
+        match Self::get_last_paid(e, &self.recipient, token) {
+            Some(last_paid) => ledger >= last_paid.saturating_add(self.period),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Allocation {
+    pub shareholder: Address,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Recoupment {
+    pub shareholder: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Distribution {
+    pub token: Address,
+    /// Total amount of tokens that is distributed
+    pub amount: i128,
+    /// Amount of tokens paid to the fee recipient
+    pub fee: i128,
+    /// Amount of tokens paid to the keeper
+    pub keeper_bounty: i128,
+    /// Fixed amounts of tokens allocated to the recipients before the tiers
+    pub fixed_amounts: Vec<Allocation>,
+    /// Amounts of tokens distributed to each tier
+    pub tiers: Vec<i128>,
+    /// Amounts of tokens allocated to the shareholders
+    pub allocations: Vec<Allocation>,
+    /// Amounts of tokens diverted from the shareholders to repay their advances
+    pub recoupments: Vec<Recoupment>,
+    /// Share table the distribution was split with
+    pub share_table: ShareTable,
+    /// Version of the share table the distribution was split with
+    pub share_version: u32,
+}
+
+/// Share table used to split a distribution
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ShareTable {
+    /// Default share table
+    Default,
+    /// Share table of the tag
+    Tag(Symbol),
+    /// Share table overriding the default share table for the token
+    Token(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MerkleRootDataKey {
+    /// Root of the merkle tree of double hashed (index, recipient, amount) leaves
+    pub root: BytesN<32>,
+    /// Amount of the token reserved for the claims
+    pub total: i128,
+    /// Amount of the token claimed so far
+    pub claimed: i128,
+    /// Last ledger sequence the recipients can claim at
+    pub deadline: u32,
+    /// Whether the unclaimed amount has been recovered after the deadline
+    pub recovered: bool,
+}
+impl MerkleRootDataKey {
+    /// Saves the merkle root as the next epoch of the token and returns the epoch
+    pub fn add_root(e: &Env, token: &Address, merkle_root: &MerkleRootDataKey) -> u32 {
+        let epoch = Self::get_epoch_count(e, token);
+        Self::save_root(e, token, epoch, merkle_root);
+
+        let key = DataKey::MerkleEpochs(token.clone());
+        e.storage().persistent().set(&key, &(epoch + 1));
+        bump_persistent(e, &key);
+
+        epoch
+    }
+
+    /// Saves the merkle root of the token for the epoch
+    pub fn save_root(e: &Env, token: &Address, epoch: u32, merkle_root: &MerkleRootDataKey) {
+        let key = DataKey::MerkleRoot(token.clone(), epoch);
+        e.storage().persistent().set(&key, merkle_root);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the merkle root of the token for the epoch
+    pub fn get_root(e: &Env, token: &Address, epoch: u32) -> Option<MerkleRootDataKey> {
+        let key = DataKey::MerkleRoot(token.clone(), epoch);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(merkle_root) => {
+                bump_persistent(e, &key);
+                Some(merkle_root)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the number of merkle roots posted for the token
+    pub fn get_epoch_count(e: &Env, token: &Address) -> u32 {
+        let key = DataKey::MerkleEpochs(token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(count) => {
+                bump_persistent(e, &key);
+                count
+            }
+            None => 0,
+        }
+    }
+
+    /// Marks the leaf at the index as claimed
+    ///
+    /// Claims are kept in bitmaps of 128 leaves.
+    pub fn set_claimed(e: &Env, token: &Address, epoch: u32, index: u32) {
+        let key = DataKey::MerkleClaimed(token.clone(), epoch, index / 128);
+        let bitmap: u128 = e.storage().persistent().get(&key).unwrap_or(0);
+        e.storage()
+            .persistent()
+            .set(&key, &(bitmap | (1 << (index % 128))));
+        bump_persistent(e, &key);
+    }
+
+    /// Returns whether the leaf at the index is claimed
+    pub fn is_claimed(e: &Env, token: &Address, epoch: u32, index: u32) -> bool {
+        let key = DataKey::MerkleClaimed(token.clone(), epoch, index / 128);
+        let res = e.storage().persistent().get::<DataKey, u128>(&key);
+        match res {
+            Some(bitmap) => {
+                bump_persistent(e, &key);
+                bitmap & (1 << (index % 128)) != 0
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistributionDataKey {}
+impl DistributionDataKey {
+    /// Saves the ledger sequence of the last distribution for the token
+    pub fn save_last_distribution(e: &Env, token: &Address, ledger: u32) {
+        let key = DataKey::LastDistribution(token.clone());
+        e.storage().persistent().set(&key, &ledger);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the ledger sequence of the last distribution for the token
+    pub fn get_last_distribution(e: &Env, token: &Address) -> Option<u32> {
+        let key = DataKey::LastDistribution(token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(ledger) => {
+                bump_persistent(e, &key);
+                Some(ledger)
+            }
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct StreamDataKey {
+    pub amount: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+impl StreamDataKey {
+    /// Returns the amount of the stream that is not released yet
+    pub fn locked_amount(&self, ledger: u32) -> i128 {
+        if ledger >= self.end_ledger {
+            return 0;
+        }
+        if ledger <= self.start_ledger {
+            return self.amount;
+        }
+        let elapsed = (ledger - self.start_ledger) as i128;
+        let duration = (self.end_ledger - self.start_ledger) as i128;
+        self.amount - self.amount.fixed_mul_floor(elapsed, duration).unwrap_or(0)
+    }
+
+    /// Starts a new stream for the shareholder
+    ///
+    /// Streams that are fully released are removed on execution.
+    pub fn add_stream(
+        e: &Env,
+        shareholder: &Address,
+        token: &Address,
+        amount: i128,
+        duration: u32,
+    ) {
+        let ledger = e.ledger().sequence();
+
+        let mut streams: Vec<StreamDataKey> = Vec::new(e);
+        for stream in Self::get_streams(e, shareholder, token).iter() {
+            if stream.locked_amount(ledger) > 0 {
+                streams.push_back(stream);
+            }
+        }
+        streams.push_back(StreamDataKey {
+            amount,
+            start_ledger: ledger,
+            end_ledger: ledger.saturating_add(duration),
+        });
+
+        let key = DataKey::Streams(shareholder.clone(), token.clone());
+        e.storage().persistent().set(&key, &streams);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the streams of the shareholder for the token
+    pub fn get_streams(e: &Env, shareholder: &Address, token: &Address) -> Vec<StreamDataKey> {
+        let key = DataKey::Streams(shareholder.clone(), token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<StreamDataKey>>(&key);
+        match res {
+            Some(streams) => {
+                bump_persistent(e, &key);
+                streams
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    /// Lowers the amount locked in the streams of the shareholder to the maximum
+    ///
+    /// The newest streams are trimmed first. A trimmed stream restarts from the current ledger
+    /// with what is left locked and keeps its end ledger. Released streams are removed.
+    pub fn trim_streams(e: &Env, shareholder: &Address, token: &Address, max_locked: i128) {
+        let ledger = e.ledger().sequence();
+        let mut excess = Self::get_locked_amount(e, shareholder, token) - max_locked;
+        if excess <= 0 {
+            return;
+        };
+
+        let mut streams: Vec<StreamDataKey> = Vec::new(e);
+        for stream in Self::get_streams(e, shareholder, token).iter().rev() {
+            let locked = stream.locked_amount(ledger);
+            if locked == 0 {
+                continue;
+            };
+
+            let trimmed = locked.min(excess);
+            excess -= trimmed;
+            if trimmed == 0 {
+                streams.push_front(stream);
+            } else if locked > trimmed {
+                streams.push_front(StreamDataKey {
+                    amount: locked - trimmed,
+                    start_ledger: ledger,
+                    end_ledger: stream.end_ledger,
+                });
+            };
+        }
+
+        if streams.is_empty() {
+            Self::remove_streams(e, shareholder, token);
+        } else {
+            let key = DataKey::Streams(shareholder.clone(), token.clone());
+            e.storage().persistent().set(&key, &streams);
+            bump_persistent(e, &key);
+        };
+    }
+
+    /// Removes the streams of the shareholder for the token
+    pub fn remove_streams(e: &Env, shareholder: &Address, token: &Address) {
+        let key = DataKey::Streams(shareholder.clone(), token.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the total amount that is still locked in the streams of the shareholder
+    pub fn get_locked_amount(e: &Env, shareholder: &Address, token: &Address) -> i128 {
+        let ledger = e.ledger().sequence();
+        Self::get_streams(e, shareholder, token)
+            .iter()
+            .fold(0, |acc, stream| acc + stream.locked_amount(ledger))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DepositDataKey {
+    pub amount: i128,
+    /// Ledger sequence of the deposit
+    pub ledger: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContributionDataKey {
+    /// Lifetime amount of the token deposited by the payer
+    pub total: i128,
+    /// Number of deposits of the token made by the payer
+    pub deposits: u32,
+}
+impl ContributionDataKey {
+    /// Records a deposit made by the payer, adding the payer to the list of payers if it is new
+    pub fn add_deposit(
+        e: &Env,
+        payer: &Address,
+        token: &Address,
+        amount: i128,
+        memo: &Option<String>,
+    ) {
+        let contribution = Self::get_contribution(e, payer, token);
+
+        let key = DataKey::Deposit(payer.clone(), token.clone(), contribution.deposits);
+        e.storage().persistent().set(
+            &key,
+            &DepositDataKey {
+                amount,
+                ledger: e.ledger().sequence(),
+            },
+        );
+        bump_persistent(e, &key);
+
+        // Only keep the memos that were given with the deposit
+        if let Some(memo) = memo {
+            let key = DataKey::DepositMemo(payer.clone(), token.clone(), contribution.deposits);
+            e.storage().persistent().set(&key, memo);
+            bump_persistent(e, &key);
+        };
+
+        let key = DataKey::Contribution(payer.clone(), token.clone());
+        e.storage().persistent().set(
+            &key,
+            &ContributionDataKey {
+                total: contribution.total + amount,
+                deposits: contribution.deposits + 1,
+            },
+        );
+        bump_persistent(e, &key);
+
+        Self::add_payer(e, payer);
+    }
+
+    /// Returns the lifetime contribution of the payer for the token
+    pub fn get_contribution(e: &Env, payer: &Address, token: &Address) -> ContributionDataKey {
+        let key = DataKey::Contribution(payer.clone(), token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(contribution) => {
+                bump_persistent(e, &key);
+                contribution
+            }
+            None => ContributionDataKey {
+                total: 0,
+                deposits: 0,
+            },
+        }
+    }
+
+    /// Returns a deposit made by the payer
+    pub fn get_deposit(
+        e: &Env,
+        payer: &Address,
+        token: &Address,
+        index: u32,
+    ) -> Option<DepositDataKey> {
+        let key = DataKey::Deposit(payer.clone(), token.clone(), index);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(deposit) => {
+                bump_persistent(e, &key);
+                Some(deposit)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the memo of a deposit made by the payer
+    pub fn get_deposit_memo(
+        e: &Env,
+        payer: &Address,
+        token: &Address,
+        index: u32,
+    ) -> Option<String> {
+        let key = DataKey::DepositMemo(payer.clone(), token.clone(), index);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(memo) => {
+                bump_persistent(e, &key);
+                Some(memo)
+            }
+            None => None,
+        }
+    }
+
+    fn add_payer(e: &Env, payer: &Address) {
+        let key = DataKey::PayerIndex(payer.clone());
+        if e.storage().persistent().has(&key) {
+            bump_persistent(e, &key);
+            return;
+        };
+
+        let count = Self::get_payer_count(e);
+        e.storage().persistent().set(&key, &count);
+        bump_persistent(e, &key);
+
+        let key = DataKey::Payer(count);
+        e.storage().persistent().set(&key, payer);
+        bump_persistent(e, &key);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::PayerCount, &(count + 1));
+        bump_instance(e);
+    }
+
+    /// Returns the number of payers that made a deposit
+    pub fn get_payer_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::PayerCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the payer at the index in the order of their first deposit
+    pub fn get_payer(e: &Env, index: u32) -> Option<Address> {
+        let key = DataKey::Payer(index);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(payer) => {
+                bump_persistent(e, &key);
+                Some(payer)
+            }
+            None => None,
+        }
+    }
+}
+
+/// Kind of correction made to an allocation
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum CorrectionKind {
+    /// The allocation is set to a corrected amount, the difference comes from or
+    /// returns to the unused balance
+    Adjustment,
+    /// Part of the allocation is transferred out of the contract to the address
+    Clawback(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CorrectionDataKey {
+    pub kind: CorrectionKind,
+    pub shareholder: Address,
+    pub token: Address,
+    /// Allocation before the correction
+    pub previous: i128,
+    /// Allocation after the correction
+    pub allocation: i128,
+    /// Reason given by the admin for the correction
+    pub reason: String,
+    /// Ledger sequence of the correction
+    pub ledger: u32,
+}
+impl CorrectionDataKey {
+    /// Appends the correction to the log and returns its index
+    pub fn add_correction(e: &Env, correction: &CorrectionDataKey) -> u32 {
+        let index = Self::get_correction_count(e);
+
+        let key = DataKey::Correction(index);
+        e.storage().persistent().set(&key, correction);
+        bump_persistent(e, &key);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::CorrectionCount, &(index + 1));
+
+        index
+    }
+
+    /// Returns the number of corrections in the log
+    pub fn get_correction_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CorrectionCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the correction at the index of the log
+    pub fn get_correction(e: &Env, index: u32) -> Option<CorrectionDataKey> {
+        let key = DataKey::Correction(index);
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(correction) => {
+                bump_persistent(e, &key);
+                Some(correction)
+            }
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Config,
+    /// Data key for keeping the arbiter that can freeze allocations
+    Arbiter,
+    /// Data key for keeping the fee carved out of every distribution
+    Fee,
+    /// Data key for keeping the bounty paid to the keepers calling the distribution
+    KeeperBounty,
+    // Storage keys for the shareholder and share data
+    //
+    /// Data key for keeping all of the shareholders in the contract
+    Shareholders,
+    /// Data key for keeping the share of a shareholder.
+    /// User addresses are mapped to their shares
+    Share(Address),
+    /// Data key for keeping the id of the latest share table version
+    CurrentShareVersion,
+    /// Data key for keeping the id of the latest version of a share table.
+    ///
+    /// ShareTable -> VersionId
+    TableVersion(ShareTable),
+    /// Data key for keeping the past and current versions of the share table.
+    ///
+    /// VersionId -> ShareVersionDataKey
+    ShareVersion(u32),
+    /// Data key for keeping the share tables scheduled to replace the default share table
+    ScheduledShares,
+    /// Data key for keeping the tags that have a share table
+    Tags,
+    /// Data key for keeping the share table of a tag.
+    ///
+    /// Tag -> Vec<ShareDataKey>
+    TaggedShares(Symbol),
+    /// Data key for keeping the tokens that have a share table override
+    TokenSharesTokens,
+    /// Data key for keeping the share table overriding the default share table for a token.
+    ///
+    /// TokenAddr -> Vec<ShareDataKey>
+    TokenShares(Address),
+    // Storage keys for the share token
+    //
+    /// Data key for keeping the metadata of the share token
+    ShareToken,
+    /// Data key for keeping the share a spender can transfer on behalf of a shareholder.
+    ///
+    /// (OwnerAddr, SpenderAddr) -> AllowanceDataKey
+    Allowance(Address, Address),
+    // Storage keys for the allocations
+    //
+    /// Data key for keeping the total allocation amount for a token.
+    /// Token addresses are mapped to their total allocation amount.
+    TotalAllocation(Address),
+    /// Data key for keeping the allocation amount for a shareholder.
+    /// User addresses with token addresses are mapped to their allocation amount.
+    ///
+    /// (UserAddr, TokenAddr) -> Allocation
+    Allocation(Address, Address),
+    /// Data key for keeping the ledger sequence the claim window of an allocation starts from.
+    /// It is the first credit or the last withdrawal of the allocation.
+    ///
+    /// (UserAddr, TokenAddr) -> Ledger sequence
+    LastClaim(Address, Address),
+    /// Data key for keeping the active distribution streams of a shareholder.
+    ///
+    /// (UserAddr, TokenAddr) -> Vec<StreamDataKey>
+    Streams(Address, Address),
+    /// Data key for keeping the shareholders that distributions are cascaded to.
+    /// User addresses are mapped to their cascade flag.
+    Cascade(Address),
+    /// Data key for keeping the shareholders that are notified of their allocations.
+    /// User addresses are mapped to their callback flag.
+    Callback(Address),
+    /// Data key for keeping the address receiving the payouts of a shareholder.
+    /// User addresses are mapped to their payout address.
+    PayoutAddress(Address),
+    /// Data key for keeping the operators withdrawing on behalf of a shareholder.
+    ///
+    /// (UserAddr, OperatorAddr) -> OperatorDataKey
+    Operator(Address, Address),
+    /// Data key for keeping the freeze of a shareholder's allocations.
+    /// User addresses are mapped to their FreezeDataKey.
+    Freeze(Address),
+    /// Data key for keeping the nonce of the next signed claim of a shareholder.
+    /// User addresses are mapped to their nonce.
+    ClaimNonce(Address),
+    // Storage keys for the payout caps
+    //
+    /// Data key for keeping the payout cap of a shareholder for a token.
+    ///
+    /// (UserAddr, TokenAddr) -> Cap
+    PayoutCap(Address, Address),
+    /// Data key for keeping the lifetime amount of a token received by a shareholder.
+    ///
+    /// (UserAddr, TokenAddr) -> Received amount
+    Received(Address, Address),
+    // Storage keys for the advances
+    //
+    /// Data key for keeping the outstanding advance of a shareholder for a token.
+    ///
+    /// (UserAddr, TokenAddr) -> RecoupmentDataKey
+    Recoupment(Address, Address),
+    // Storage keys for the fixed amounts
+    //
+    /// Data key for keeping the fixed amounts paid for a token.
+    ///
+    /// TokenAddr -> Vec<FixedAmountDataKey>
+    FixedAmounts(Address),
+    /// Data key for keeping the ledger sequence of the last fixed amount payment.
+    ///
+    /// (RecipientAddr, TokenAddr) -> Ledger sequence
+    FixedAmountPaid(Address, Address),
+    // Storage keys for the tiers
+    //
+    /// Data key for keeping the ordered list of tiers
+    Tiers,
+    /// Data key for keeping the amount of a token that is distributed to a tier.
+    ///
+    /// (TierIndex, TokenAddr) -> Filled amount
+    TierFilled(u32, Address),
+    // Storage keys for the contributions
+    //
+    /// Data key for keeping the lifetime contribution of a payer for a token.
+    ///
+    /// (PayerAddr, TokenAddr) -> ContributionDataKey
+    Contribution(Address, Address),
+    /// Data key for keeping a deposit made by a payer.
+    ///
+    /// (PayerAddr, TokenAddr, DepositIndex) -> DepositDataKey
+    Deposit(Address, Address, u32),
+    /// Data key for keeping the memo given with a deposit
+    ///
+    /// (PayerAddr, TokenAddr, DepositIndex) -> String
+    DepositMemo(Address, Address, u32),
+    /// Data key for keeping the number of payers
+    PayerCount,
+    /// Data key for keeping the payers in the order of their first deposit.
+    ///
+    /// PayerIndex -> PayerAddr
+    Payer(u32),
+    /// Data key for keeping the index of a payer.
+    ///
+    /// PayerAddr -> PayerIndex
+    PayerIndex(Address),
+    // Storage keys for the distributions
+    //
+    /// Data key for keeping the ledger sequence of the last distribution for a token.
+    /// Token addresses are mapped to their last distribution ledger.
+    LastDistribution(Address),
+    /// Data key for keeping the tokens settled before the share of a shareholder changes.
+    /// User addresses are mapped to their tokens.
+    SettlementTokens(Address),
+    // Storage keys for the allocation corrections
+    //
+    /// Data key for keeping the number of corrections in the log
+    CorrectionCount,
+    /// Data key for keeping the corrections made by the admin in order.
+    ///
+    /// CorrectionIndex -> CorrectionDataKey
+    Correction(u32),
+    // Storage keys for the merkle distributions
+    //
+    /// Data key for keeping the number of merkle roots posted for a token.
+    /// Token addresses are mapped to their epoch count.
+    MerkleEpochs(Address),
+    /// Data key for keeping the merkle root of a token for an epoch.
+    ///
+    /// (TokenAddr, Epoch) -> MerkleRootDataKey
+    MerkleRoot(Address, u32),
+    /// Data key for keeping the bitmaps of the claimed leaves.
+    ///
+    /// (TokenAddr, Epoch, Index / 128) -> Bitmap
+    MerkleClaimed(Address, u32, u32),
 }
@@ -1,7 +1,43 @@
+mod adjust_allocation;
+mod approve;
+mod approve_operator;
+mod cascade_tokens;
+mod claim_merkle;
+mod claim_with_signature;
+mod clawback_allocation;
+mod deposit;
 mod distribute;
+mod enable_share_token;
+mod freeze_allocation;
+mod get_contributions;
+mod get_shares_at_version;
 mod helpers;
 mod init;
 mod lock_contract;
+mod post_merkle_root;
+mod preview_distribution;
+mod reclaim_allocation;
+mod record_advance;
+mod recover_merkle_funds;
+mod renounce_share;
+mod schedule_shares;
+mod transfer;
+mod transfer_from;
+mod transfer_share;
 mod transfer_tokens;
+mod update_callback;
+mod update_cascade;
+mod update_claim_window;
+mod update_fee;
+mod update_fixed_amounts;
+mod update_keeper_bounty;
+mod update_payout_address;
+mod update_payout_cap;
+mod update_settlement_tokens;
 mod update_shares;
+mod update_shares_transferable;
+mod update_stream_duration;
+mod update_tagged_shares;
+mod update_tiers;
+mod update_token_shares;
 mod withdraw_allocation;
@@ -0,0 +1,191 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String,
+};
+
+use crate::{
+    errors::Error,
+    storage::{CorrectionDataKey, CorrectionKind},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shares = splitter.list_shares(&None);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_2 = shares.get(1).unwrap().shareholder;
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    let reason = String::from_str(&env, "Miscalculated royalties");
+
+    // Lowering an allocation releases the tokens back to the unused balance
+    splitter.adjust_allocation(&shareholder_1, &token_address, &700, &reason);
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 700);
+
+    let correction = CorrectionDataKey {
+        kind: CorrectionKind::Adjustment,
+        shareholder: shareholder_1.clone(),
+        token: token_address.clone(),
+        previous: 805,
+        allocation: 700,
+        reason: reason.clone(),
+        ledger: env.ledger().sequence(),
+    };
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("correct"), token_address.clone()).into_val(&env),
+                (0_u32, correction.clone()).into_val(&env)
+            )
+        ]
+    );
+
+    // Raising an allocation takes the tokens from the unused balance
+    assert_eq!(
+        splitter.try_adjust_allocation(&shareholder_2, &token_address, &301, &reason),
+        Err(Ok(Error::TransferAmountAboveUnusedBalance))
+    );
+    splitter.adjust_allocation(&shareholder_2, &token_address, &300, &reason);
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 300);
+    assert!(splitter
+        .try_transfer_tokens(&token_address, &admin, &1)
+        .is_err());
+
+    // Removing an allocation entirely
+    splitter.adjust_allocation(&shareholder_2, &token_address, &0, &reason);
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 0);
+    splitter.transfer_tokens(&token_address, &admin, &300);
+
+    let corrections = splitter.list_corrections(&0, &10);
+    assert_eq!(corrections.len(), 3);
+    assert_eq!(corrections.get(0).unwrap(), correction);
+    assert_eq!(corrections.get(2).unwrap().previous, 300);
+    assert_eq!(corrections.get(2).unwrap().allocation, 0);
+    assert_eq!(splitter.list_corrections(&1, &1).len(), 1);
+}
+
+#[test]
+fn test_streamed_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_stream_duration(&1_000);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Lowering the allocation trims the streams so they cannot lock more than the allocation
+    splitter.adjust_allocation(
+        &shareholder,
+        &token_address,
+        &100_000,
+        &String::from_str(&env, "Miscalculated royalties"),
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 500);
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &50_001, &None),
+        Err(Ok(Error::WithdrawalAmountAboveReleasedAllocation))
+    );
+    splitter.withdraw_allocation(&token_address, &shareholder, &50_000, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    splitter.withdraw_allocation(&token_address, &shareholder, &50_000, &None);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_adjust_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &String::from_str(&env, "")
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_list_corrections(&0, &10),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_adjust_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &String::from_str(&env, "")
+        )
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_adjust_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &String::from_str(&env, "")
+        ),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_negative_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_adjust_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &-1,
+            &String::from_str(&env, "")
+        ),
+        Err(Ok(Error::InvalidAllocation))
+    );
+}
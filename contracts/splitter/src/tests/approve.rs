@@ -0,0 +1,90 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String,
+};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    splitter.approve(&owner, &spender, &1000, &100);
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("approve"), owner.clone(), spender.clone()).into_val(&env),
+                (1000_i128, 100_u32).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(splitter.allowance(&owner, &spender), 1000);
+
+    // Allowances are not valid after their expiration ledger
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(splitter.allowance(&owner, &spender), 0);
+
+    // An amount of 0 revokes the allowance
+    splitter.approve(&owner, &spender, &1000, &200);
+    splitter.approve(&owner, &spender, &0, &0);
+    assert_eq!(splitter.allowance(&owner, &spender), 0);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_approve(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1000,
+            &100
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    assert_eq!(
+        splitter.try_approve(&owner, &spender, &-1, &100),
+        Err(Ok(Error::InvalidAllowanceAmount))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_approve(&owner, &spender, &1000, &99),
+        Err(Ok(Error::DeadlinePassed))
+    );
+}
@@ -0,0 +1,163 @@
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Ledger},
+    Address, Env, Map,
+};
+
+use crate::{
+    errors::Error,
+    storage::OperatorDataKey,
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token, get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    splitter.approve_operator(
+        &shareholder,
+        &operator,
+        &100,
+        &map![&env, (token_address.clone(), 500_000_000)],
+    );
+
+    // The operator withdraws on behalf of the shareholder within its limit
+    splitter.withdraw_allocation(
+        &token_address,
+        &shareholder,
+        &300_000_000,
+        &Some(operator.clone()),
+    );
+    assert_eq!(token.balance(&shareholder), 300_000_000);
+    assert_eq!(token.balance(&operator), 0);
+    assert_eq!(
+        splitter.get_operator(&shareholder, &operator),
+        Some(OperatorDataKey {
+            expiration_ledger: 100,
+            limits: map![&env, (token_address.clone(), 200_000_000)],
+        })
+    );
+
+    assert_eq!(
+        splitter.try_withdraw_allocation(
+            &token_address,
+            &shareholder,
+            &200_000_001,
+            &Some(operator.clone())
+        ),
+        Err(Ok(Error::WithdrawalAmountAboveOperatorLimit))
+    );
+
+    // Revoked operators cannot withdraw anymore
+    splitter.revoke_operator(&shareholder, &operator);
+    assert_eq!(splitter.get_operator(&shareholder, &operator), None);
+    assert_eq!(
+        splitter.try_withdraw_allocation(
+            &token_address,
+            &shareholder,
+            &100_000_000,
+            &Some(operator.clone())
+        ),
+        Err(Ok(Error::OperatorNotApproved))
+    );
+}
+
+#[test]
+fn test_expired_operator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Operators without a token limit can withdraw the whole allocation
+    splitter.approve_operator(&shareholder, &operator, &100, &Map::new(&env));
+    splitter.withdraw_allocation(
+        &token_address,
+        &shareholder,
+        &100_000_000,
+        &Some(operator.clone()),
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+
+    assert_eq!(
+        splitter.try_withdraw_allocation(
+            &token_address,
+            &shareholder,
+            &100_000_000,
+            &Some(operator.clone())
+        ),
+        Err(Ok(Error::OperatorNotApproved))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_approve_operator(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &100,
+            &Map::new(&env)
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_operator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_approve_operator(&shareholder, &operator, &99, &Map::new(&env)),
+        Err(Ok(Error::DeadlinePassed))
+    );
+
+    assert_eq!(
+        splitter.try_approve_operator(
+            &shareholder,
+            &operator,
+            &200,
+            &map![&env, (Address::generate(&env), 0)]
+        ),
+        Err(Ok(Error::InvalidOperatorLimit))
+    );
+}
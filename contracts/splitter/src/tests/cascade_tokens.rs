@@ -0,0 +1,135 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ShareDataKey, MAX_CASCADE_DEPTH},
+    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let sender = Address::generate(&env);
+    sudo_token.mint(&sender, &1_000_000_000);
+
+    // Unused balance of the splitter is not distributed
+    sudo_token.mint(&splitter_address, &500_000_000);
+
+    splitter.cascade_tokens(&sender, &token_address, &1_000_000_000, &1);
+
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&splitter_address), 1_500_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        195_000_000
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_cascade_tokens(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &1
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_cascade_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_cascade_tokens(&Address::generate(&env), &Address::generate(&env), &0, &1),
+        Err(Ok(Error::InvalidCascadeAmount))
+    );
+}
+
+#[test]
+fn test_cascade_depth_exceeded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_cascade_tokens(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &(MAX_CASCADE_DEPTH + 1)
+        ),
+        Err(Ok(Error::CascadeDepthExceeded))
+    );
+}
@@ -0,0 +1,142 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env,
+};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_merkle_leaf, create_splitter, create_splitter_with_default_shares, create_token,
+        hash_merkle_pair,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipients = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let leaves = [
+        create_merkle_leaf(&env, 0, &recipients[0], 100),
+        create_merkle_leaf(&env, 1, &recipients[1], 200),
+        create_merkle_leaf(&env, 2, &recipients[2], 300),
+    ];
+    let node = hash_merkle_pair(&env, &leaves[0], &leaves[1]);
+    let root = hash_merkle_pair(&env, &node, &leaves[2]);
+
+    splitter.post_merkle_root(&token_address, &root, &600, &100);
+
+    splitter.claim_merkle(
+        &token_address,
+        &0,
+        &1,
+        &recipients[1],
+        &200,
+        &vec![&env, leaves[0].clone(), leaves[2].clone()],
+    );
+    splitter.claim_merkle(
+        &token_address,
+        &0,
+        &2,
+        &recipients[2],
+        &300,
+        &vec![&env, node.clone()],
+    );
+
+    assert_eq!(token.balance(&recipients[1]), 200);
+    assert_eq!(token.balance(&recipients[2]), 300);
+    assert!(!splitter.is_merkle_claimed(&token_address, &0, &0));
+    assert!(splitter.is_merkle_claimed(&token_address, &0, &1));
+    assert_eq!(splitter.get_merkle_root(&token_address, &0).claimed, 500);
+
+    // Leaves cannot be claimed twice
+    assert_eq!(
+        splitter.try_claim_merkle(
+            &token_address,
+            &0,
+            &2,
+            &recipients[2],
+            &300,
+            &vec![&env, node.clone()],
+        ),
+        Err(Ok(Error::MerkleAlreadyClaimed))
+    );
+
+    // Leaves that are not in the tree are rejected
+    assert_eq!(
+        splitter.try_claim_merkle(
+            &token_address,
+            &0,
+            &0,
+            &recipients[0],
+            &200,
+            &vec![&env, leaves[1].clone(), leaves[2].clone()],
+        ),
+        Err(Ok(Error::InvalidMerkleProof))
+    );
+
+    // Claims are closed after the deadline
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(
+        splitter.try_claim_merkle(
+            &token_address,
+            &0,
+            &0,
+            &recipients[0],
+            &100,
+            &vec![&env, leaves[1].clone(), leaves[2].clone()],
+        ),
+        Err(Ok(Error::DeadlinePassed))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_claim_merkle(
+            &Address::generate(&env),
+            &0,
+            &0,
+            &Address::generate(&env),
+            &100,
+            &vec![&env],
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_merkle_root_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_claim_merkle(
+            &Address::generate(&env),
+            &0,
+            &0,
+            &Address::generate(&env),
+            &100,
+            &vec![&env, BytesN::from_array(&env, &[1; 32])],
+        ),
+        Err(Ok(Error::MerkleRootNotFound))
+    );
+}
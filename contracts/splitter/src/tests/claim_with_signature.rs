@@ -0,0 +1,223 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{
+        create_account, create_splitter, create_splitter_with_shares, create_token, sign,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let (signing_key, public_key, shareholder) = create_account(&env, 1);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Stellar accounts need a trustline to hold the token, the payout goes to a wallet contract
+    let wallet = Address::generate(&env);
+    splitter.update_payout_address(&shareholder, &Some(wallet.clone()));
+
+    assert_eq!(splitter.get_claim_nonce(&shareholder), 0);
+
+    let payload = splitter.get_claim_payload(&token_address, &400, &0, &100, &10);
+    let signature = sign(&env, &signing_key, &payload);
+
+    splitter.claim_with_signature(
+        &public_key,
+        &token_address,
+        &400,
+        &0,
+        &100,
+        &10,
+        &signature,
+        &Some(relayer.clone()),
+        &10,
+    );
+
+    assert_eq!(token.balance(&wallet), 390);
+    assert_eq!(token.balance(&relayer), 10);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 100);
+    assert_eq!(splitter.get_claim_nonce(&shareholder), 1);
+
+    // The same claim cannot be replayed
+    assert_eq!(
+        splitter.try_claim_with_signature(
+            &public_key,
+            &token_address,
+            &400,
+            &0,
+            &100,
+            &10,
+            &signature,
+            &Some(relayer.clone()),
+            &10,
+        ),
+        Err(Ok(Error::InvalidClaimNonce))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+    let (signing_key, public_key, _) = create_account(&env, 1);
+
+    assert_eq!(
+        splitter.try_claim_with_signature(
+            &public_key,
+            &Address::generate(&env),
+            &400,
+            &0,
+            &100,
+            &0,
+            &sign(&env, &signing_key, &Bytes::new(&env)),
+            &None,
+            &0,
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let (signing_key, public_key, shareholder) = create_account(&env, 1);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    let payload = splitter.get_claim_payload(&token_address, &400, &0, &100, &10);
+    let signature = sign(&env, &signing_key, &payload);
+
+    // The relayer cannot take more than the signed maximum
+    assert_eq!(
+        splitter.try_claim_with_signature(
+            &public_key,
+            &token_address,
+            &400,
+            &0,
+            &100,
+            &10,
+            &signature,
+            &Some(relayer.clone()),
+            &11,
+        ),
+        Err(Ok(Error::InvalidRelayerFee))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+
+    assert_eq!(
+        splitter.try_claim_with_signature(
+            &public_key,
+            &token_address,
+            &400,
+            &0,
+            &100,
+            &10,
+            &signature,
+            &Some(relayer.clone()),
+            &10,
+        ),
+        Err(Ok(Error::DeadlinePassed))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (_, public_key, shareholder) = create_account(&env, 1);
+    let (other_key, _, _) = create_account(&env, 2);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Claims signed by another key are rejected
+    let payload = splitter.get_claim_payload(&token_address, &400, &0, &100, &0);
+    splitter.claim_with_signature(
+        &public_key,
+        &token_address,
+        &400,
+        &0,
+        &100,
+        &0,
+        &sign(&env, &other_key, &payload),
+        &None,
+        &0,
+    );
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{
+    errors::Error,
+    storage::CorrectionKind,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+    let recipient = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    let reason = String::from_str(&env, "Duplicate payout");
+
+    splitter.clawback_allocation(&shareholder, &token_address, &305, &recipient, &reason);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 500);
+    assert_eq!(token.balance(&recipient), 305);
+    assert_eq!(token.balance(&splitter_address), 695);
+
+    // Clawing back the remaining allocation removes it
+    splitter.clawback_allocation(&shareholder, &token_address, &500, &recipient, &reason);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
+    assert_eq!(token.balance(&recipient), 805);
+
+    let corrections = splitter.list_corrections(&0, &10);
+    assert_eq!(corrections.len(), 2);
+
+    let correction = corrections.get(0).unwrap();
+    assert_eq!(correction.kind, CorrectionKind::Clawback(recipient.clone()));
+    assert_eq!(correction.previous, 805);
+    assert_eq!(correction.allocation, 500);
+    assert_eq!(correction.reason, reason);
+
+    // The remaining allocation is still withdrawable
+    let shareholder_2 = splitter.list_shares(&None).get(1).unwrap().shareholder;
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 195);
+    assert!(splitter
+        .try_transfer_tokens(&token_address, &admin, &1)
+        .is_err());
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_clawback_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &100,
+            &Address::generate(&env),
+            &String::from_str(&env, "")
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_clawback_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &100,
+            &Address::generate(&env),
+            &String::from_str(&env, "")
+        )
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_clawback_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &100,
+            &Address::generate(&env),
+            &String::from_str(&env, "")
+        ),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_clawback_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    let reason = String::from_str(&env, "");
+
+    assert_eq!(
+        splitter.try_clawback_allocation(
+            &shareholder,
+            &token_address,
+            &0,
+            &Address::generate(&env),
+            &reason
+        ),
+        Err(Ok(Error::ZeroWithdrawalAmount))
+    );
+    assert_eq!(
+        splitter.try_clawback_allocation(
+            &shareholder,
+            &token_address,
+            &806,
+            &Address::generate(&env),
+            &reason
+        ),
+        Err(Ok(Error::WithdrawalAmountAboveAllocation))
+    );
+}
@@ -0,0 +1,211 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, String,
+};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let payer = Address::generate(&env);
+    sudo_token.mint(&payer, &1_000_000_000);
+
+    // Unrelated balance is not swept into the deposit
+    sudo_token.mint(&splitter_address, &500_000_000);
+
+    let memo = Some(String::from_str(&env, "INV-001"));
+    splitter.deposit(&payer, &token_address, &1_000_000_000, &memo, &None);
+
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("deposit"), token_address.clone()).into_val(&env),
+                (payer.clone(), 1_000_000_000_i128, memo).into_val(&env),
+            )
+        ]
+    );
+
+    assert_eq!(token.balance(&payer), 0);
+    assert_eq!(token.balance(&splitter_address), 1_500_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        195_000_000
+    );
+}
+
+#[test]
+fn test_deposit_rounding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 3333,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3334,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 3333,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let payer = Address::generate(&env);
+    sudo_token.mint(&payer, &100);
+
+    splitter.deposit(&payer, &token_address, &100, &None, &None);
+
+    // The rounding dust goes to the largest shareholder
+    let allocation_1 = splitter.get_allocation(&shareholder_1, &token_address);
+    let allocation_2 = splitter.get_allocation(&shareholder_2, &token_address);
+    let allocation_3 = splitter.get_allocation(&shareholder_3, &token_address);
+    assert_eq!(allocation_1, 33);
+    assert_eq!(allocation_2, 34);
+    assert_eq!(allocation_3, 33);
+    assert_eq!(allocation_1 + allocation_2 + allocation_3, 100);
+}
+
+#[test]
+fn test_tagged_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    splitter.update_tagged_shares(
+        &symbol_short!("merch"),
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 2500,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 7500,
+            },
+        ],
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let payer = Address::generate(&env);
+    sudo_token.mint(&payer, &1_000_000_000);
+
+    splitter.deposit(
+        &payer,
+        &token_address,
+        &1_000_000_000,
+        &None,
+        &Some(symbol_short!("merch")),
+    );
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        250_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        750_000_000
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_deposit(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_invalid_deposit_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_deposit(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &None,
+            &None
+        ),
+        Err(Ok(Error::InvalidDepositAmount))
+    );
+}
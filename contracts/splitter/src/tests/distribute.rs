@@ -1,9 +1,19 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use soroban_sdk::{
+    map, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal,
+};
 
 use crate::{
     errors::Error,
-    storage::ShareDataKey,
-    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+    storage::{
+        Allocation, Distribution, FixedAmountDataKey, KeeperBountyDataKey, PayoutCap, Recoupment,
+        RecoupmentDataKey, ShareDataKey, ShareTable, TierDataKey,
+    },
+    tests::helpers::{
+        create_callback_receiver, create_splitter, create_splitter_with_default_shares,
+        create_splitter_with_shares, create_token,
+    },
 };
 
 #[test]
@@ -37,7 +47,7 @@ fn happy_path() {
 
     sudo_token.mint(&splitter_address, &1_000_000_000);
 
-    splitter.distribute_tokens(&token_address);
+    splitter.distribute_tokens(&token_address, &None, &None);
 
     let allocation_1 = splitter.get_allocation(&shareholder_1, &token_address);
     assert_eq!(allocation_1, 805_000_000);
@@ -45,13 +55,1434 @@ fn happy_path() {
     assert_eq!(allocation_2, 195_000_000);
 }
 
+#[test]
+fn test_distribute_unused_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Already allocated tokens are not distributed again
+    splitter.distribute_tokens(&token_address, &None, &None);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        195_000_000
+    );
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        1_610_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        390_000_000
+    );
+}
+
+#[test]
+fn test_streamed_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+    splitter.update_stream_duration(&1_000);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder_1, &token_address),
+        0
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 250);
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder_1, &token_address),
+        201_250_000
+    );
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder_2, &token_address),
+        48_750_000
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 750);
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder_2, &token_address),
+        195_000_000
+    );
+}
+
+#[test]
+fn test_streamed_distribution_end_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+    splitter.update_stream_duration(&u32::MAX);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    // The stream ends at the last ledger instead of overflowing
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder, &token_address),
+        0
+    );
+}
+
+#[test]
+fn test_keeper_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+    splitter.update_keeper_bounty(&Some(KeeperBountyDataKey {
+        fee: 100,
+        cap: Some(5_000_000),
+        min_interval: 100,
+    }));
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    let keeper = Address::generate(&env);
+
+    // 1% of the distribution is paid to the keeper
+    sudo_token.mint(&splitter_address, &100_000_000);
+    splitter.distribute_tokens(&token_address, &Some(keeper.clone()), &None);
+
+    assert_eq!(token.balance(&keeper), 1_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        79_695_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        19_305_000
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+
+    // Bounty is capped
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &Some(keeper.clone()), &None);
+
+    assert_eq!(token.balance(&keeper), 6_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        880_670_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        213_330_000
+    );
+}
+
+#[test]
+fn test_keeper_distribution_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &Some(Address::generate(&env)), &None),
+        Err(Ok(Error::KeeperDistributionDisabled))
+    );
+}
+
+#[test]
+fn test_distribution_interval_not_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    splitter.update_keeper_bounty(&Some(KeeperBountyDataKey {
+        fee: 100,
+        cap: None,
+        min_interval: 100,
+    }));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    let keeper = Address::generate(&env);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &Some(keeper.clone()), &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 99);
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &Some(keeper.clone()), &None),
+        Err(Ok(Error::DistributionIntervalNotReached))
+    );
+
+    // Admin distributions are not limited by the interval
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    splitter.distribute_tokens(&token_address, &Some(keeper), &None);
+}
+
+#[test]
+fn test_keeper_tag_not_allowed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    splitter.update_keeper_bounty(&Some(KeeperBountyDataKey {
+        fee: 100,
+        cap: None,
+        min_interval: 0,
+    }));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    assert_eq!(
+        splitter.try_distribute_tokens(
+            &token_address,
+            &Some(Address::generate(&env)),
+            &Some(symbol_short!("tag"))
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_distribution_interval_ignores_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    splitter.update_keeper_bounty(&Some(KeeperBountyDataKey {
+        fee: 100,
+        cap: None,
+        min_interval: 100,
+    }));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    let keeper = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &Some(keeper.clone()), &None);
+
+    // Permissionless deposits do not delay the keepers
+    env.ledger().with_mut(|li| li.sequence_number += 99);
+    sudo_token.mint(&payer, &1_000_000_000);
+    splitter.deposit(&payer, &token_address, &1_000_000_000, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &Some(keeper), &None);
+}
+
+#[test]
+fn test_distribution_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter(&env);
+    splitter.init(
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+        &Some(fee_recipient.clone()),
+        &250,
+        &None,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(token.balance(&fee_recipient), 25_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        784_875_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        190_125_000
+    );
+
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("distrib"), token_address.clone()).into_val(&env),
+                Distribution {
+                    token: token_address.clone(),
+                    amount: 1_000_000_000,
+                    fee: 25_000_000,
+                    keeper_bounty: 0,
+                    fixed_amounts: vec![&env],
+                    tiers: vec![&env],
+                    allocations: vec![
+                        &env,
+                        Allocation {
+                            shareholder: shareholder_1,
+                            amount: 784_875_000,
+                        },
+                        Allocation {
+                            shareholder: shareholder_2,
+                            amount: 190_125_000,
+                        },
+                    ],
+                    recoupments: vec![&env],
+                    share_table: ShareTable::Default,
+                    share_version: 1,
+                }
+                .into_val(&env),
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_tiered_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let label = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    let (_, other_sudo_token, other_token_address) = create_token(&env, &token_admin);
+
+    let tiers = vec![
+        &env,
+        TierDataKey {
+            shares: vec![
+                &env,
+                ShareDataKey {
+                    shareholder: label.clone(),
+                    share: 10000,
+                },
+            ],
+            caps: map![
+                &env,
+                (token_address.clone(), 1_000_000_000),
+                (other_token_address.clone(), 500_000_000)
+            ],
+        },
+    ];
+    splitter.update_tiers(&tiers);
+
+    // The first tier receives everything until the cap is reached
+    sudo_token.mint(&splitter_address, &600_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(splitter.get_allocation(&label, &token_address), 600_000_000);
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 600_000_000);
+
+    // The rest is split between the shareholders after the cap is reached
+    sudo_token.mint(&splitter_address, &1_400_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&label, &token_address),
+        1_000_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        195_000_000
+    );
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 1_000_000_000);
+
+    // Tiers are filled per token up to the cap of the token
+    other_sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&other_token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&label, &other_token_address),
+        500_000_000
+    );
+    assert_eq!(
+        splitter.get_tier_filled(&0, &other_token_address),
+        500_000_000
+    );
+
+    // Tokens without a cap skip the tier
+    let (_, uncapped_sudo_token, uncapped_token_address) = create_token(&env, &token_admin);
+    uncapped_sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&uncapped_token_address, &None, &None);
+
+    assert_eq!(splitter.get_allocation(&label, &uncapped_token_address), 0);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &uncapped_token_address),
+        805_000_000
+    );
+
+    // Updating the tiers keeps the fill progress of the unchanged tiers
+    splitter.update_tiers(&tiers);
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 1_000_000_000);
+    assert_eq!(
+        splitter.get_tier_filled(&0, &other_token_address),
+        500_000_000
+    );
+}
+
+#[test]
+fn test_tiers_update_after_fill() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let label = Address::generate(&env);
+    let producer = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let label_shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: label.clone(),
+            share: 10000,
+        },
+    ];
+    splitter.update_tiers(&vec![
+        &env,
+        TierDataKey {
+            shares: label_shares.clone(),
+            caps: map![&env, (token_address.clone(), 1_000_000_000)],
+        },
+    ]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 1_000_000_000);
+
+    // Adding a tier in front moves the filled tier, which keeps its progress under the raised cap
+    splitter.update_tiers(&vec![
+        &env,
+        TierDataKey {
+            shares: vec![
+                &env,
+                ShareDataKey {
+                    shareholder: producer.clone(),
+                    share: 10000,
+                },
+            ],
+            caps: map![&env, (token_address.clone(), 200_000_000)],
+        },
+        TierDataKey {
+            shares: label_shares.clone(),
+            caps: map![&env, (token_address.clone(), 1_500_000_000)],
+        },
+    ]);
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 0);
+    assert_eq!(splitter.get_tier_filled(&1, &token_address), 1_000_000_000);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&producer, &token_address),
+        200_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&label, &token_address),
+        1_500_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        150_000_000
+    );
+
+    // A tier with a changed share table starts empty
+    splitter.update_tiers(&vec![
+        &env,
+        TierDataKey {
+            shares: vec![
+                &env,
+                ShareDataKey {
+                    shareholder: label.clone(),
+                    share: 5000,
+                },
+                ShareDataKey {
+                    shareholder: producer.clone(),
+                    share: 5000,
+                },
+            ],
+            caps: map![&env, (token_address.clone(), 1_500_000_000)],
+        },
+    ]);
+    assert_eq!(splitter.get_tier_filled(&0, &token_address), 0);
+}
+
+#[test]
+fn test_payout_cap_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.update_payout_cap(&shareholder_1, &token_address, &Some(300_000_000));
+
+    // The excess above the cap is spread over the uncapped shareholders
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        300_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        420_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address),
+        280_000_000
+    );
+    assert_eq!(
+        splitter.get_payout_cap(&shareholder_1, &token_address),
+        Some(PayoutCap {
+            cap: 300_000_000,
+            received: 300_000_000
+        })
+    );
+
+    // Withdrawals do not reset the cap
+    splitter.withdraw_allocation(&token_address, &shareholder_1, &300_000_000, &None);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        1_020_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address),
+        680_000_000
+    );
+}
+
+#[test]
+fn test_recoupment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let label = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.record_advance(&shareholder_1, &token_address, &300_000_000, &label);
+
+    // The whole allocation goes towards the advance
+    sudo_token.mint(&splitter_address, &400_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(splitter.get_allocation(&label, &token_address), 200_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        200_000_000
+    );
+    assert_eq!(
+        splitter.get_recoupment(&shareholder_1, &token_address),
+        Some(RecoupmentDataKey {
+            recipient: label.clone(),
+            balance: 100_000_000
+        })
+    );
+
+    // Only the outstanding balance is diverted, the rest goes to the shareholder
+    sudo_token.mint(&splitter_address, &400_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("distrib"), token_address.clone()).into_val(&env),
+                Distribution {
+                    token: token_address.clone(),
+                    amount: 400_000_000,
+                    fee: 0,
+                    keeper_bounty: 0,
+                    fixed_amounts: vec![&env],
+                    tiers: vec![&env],
+                    allocations: vec![
+                        &env,
+                        Allocation {
+                            shareholder: shareholder_1.clone(),
+                            amount: 100_000_000,
+                        },
+                        Allocation {
+                            shareholder: shareholder_2.clone(),
+                            amount: 200_000_000,
+                        },
+                        Allocation {
+                            shareholder: label.clone(),
+                            amount: 100_000_000,
+                        },
+                    ],
+                    recoupments: vec![
+                        &env,
+                        Recoupment {
+                            shareholder: shareholder_1.clone(),
+                            recipient: label.clone(),
+                            amount: 100_000_000,
+                        },
+                    ],
+                    share_table: ShareTable::Default,
+                    share_version: 1,
+                }
+                .into_val(&env),
+            )
+        ]
+    );
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        100_000_000
+    );
+    assert_eq!(splitter.get_allocation(&label, &token_address), 300_000_000);
+    assert_eq!(
+        splitter.get_recoupment(&shareholder_1, &token_address),
+        None
+    );
+}
+
+#[test]
+fn test_fixed_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let service = Address::generate(&env);
+    let subscription = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.update_fixed_amounts(
+        &token_address,
+        &vec![
+            &env,
+            FixedAmountDataKey {
+                recipient: service.clone(),
+                amount: 50_000_000,
+                period: 0,
+            },
+            FixedAmountDataKey {
+                recipient: subscription.clone(),
+                amount: 150_000_000,
+                period: 1000,
+            },
+        ],
+    );
+
+    // Both fixed amounts are due on the first distribution
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&service, &token_address),
+        50_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        150_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        400_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        400_000_000
+    );
+
+    // The periodic amount is not due again until the period has passed
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&service, &token_address),
+        100_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        150_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        875_000_000
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1000);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&service, &token_address),
+        150_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        300_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        1_275_000_000
+    );
+}
+
+#[test]
+fn test_fixed_amounts_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let service = Address::generate(&env);
+    let subscription = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.update_fixed_amounts(
+        &token_address,
+        &vec![
+            &env,
+            FixedAmountDataKey {
+                recipient: service.clone(),
+                amount: 500_000_000,
+                period: 1000,
+            },
+            FixedAmountDataKey {
+                recipient: subscription.clone(),
+                amount: 50_000_000,
+                period: 1000,
+            },
+        ],
+    );
+
+    // The balance cannot cover the service, which also holds back the later subscription
+    sudo_token.mint(&splitter_address, &100_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(splitter.get_allocation(&service, &token_address), 0);
+    assert_eq!(splitter.get_allocation(&subscription, &token_address), 0);
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        50_000_000
+    );
+
+    // Both stay due and are paid in order once the balance allows it
+    sudo_token.mint(&splitter_address, &650_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&service, &token_address),
+        500_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        50_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        100_000_000
+    );
+}
+
+#[test]
+fn test_fixed_amounts_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let subscription = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.update_fixed_amounts(
+        &token_address,
+        &vec![
+            &env,
+            FixedAmountDataKey {
+                recipient: subscription.clone(),
+                amount: 100_000_000,
+                period: 1000,
+            },
+        ],
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // A late distribution does not push the schedule back
+    env.ledger().with_mut(|li| li.sequence_number = 1600);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        200_000_000
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 2100);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&subscription, &token_address),
+        300_000_000
+    );
+}
+
+#[test]
+fn test_cascade_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let child_shareholder_1 = Address::generate(&env);
+    let child_shareholder_2 = Address::generate(&env);
+
+    let (child, child_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: child_shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: child_shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: child_address.clone(),
+                share: 4000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    assert!(splitter.is_splitter());
+    splitter.update_cascade(&child_address, &true);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // The allocation of the child is forwarded and distributed by the child
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        600_000_000
+    );
+    assert_eq!(splitter.get_allocation(&child_address, &token_address), 0);
+    assert_eq!(token.balance(&splitter_address), 600_000_000);
+    assert_eq!(token.balance(&child_address), 400_000_000);
+    assert_eq!(
+        child.get_allocation(&child_shareholder_1, &token_address),
+        200_000_000
+    );
+    assert_eq!(
+        child.get_allocation(&child_shareholder_2, &token_address),
+        200_000_000
+    );
+}
+
+#[test]
+fn test_cascade_fallback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+
+    // The child splitter is not initialized, so it cannot distribute the cascaded amount
+    let (_, child_address) = create_splitter(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: child_address.clone(),
+                share: 4000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    splitter.update_cascade(&child_address, &true);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&child_address, &token_address),
+        400_000_000
+    );
+    assert_eq!(token.balance(&splitter_address), 1_000_000_000);
+    assert_eq!(token.balance(&child_address), 0);
+}
+
+#[test]
+fn test_allocation_callback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (receiver, receiver_address) = create_callback_receiver(&env);
+
+    // Uninitialized splitter without the callback function
+    let (_, failing_address) = create_splitter(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: receiver_address.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: failing_address.clone(),
+                share: 4000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Recipients are not notified unless they opt in
+    assert_eq!(receiver.notified(&token_address), 0);
+
+    splitter.update_callback(&receiver_address, &true);
+    splitter.update_callback(&failing_address, &true);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // The failing callback does not revert the distribution
+    assert_eq!(receiver.notified(&token_address), 600_000_000);
+    assert_eq!(
+        splitter.get_allocation(&receiver_address, &token_address),
+        1_200_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&failing_address, &token_address),
+        800_000_000
+    );
+}
+
+#[test]
+fn test_tagged_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let default_shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    splitter.update_tagged_shares(
+        &symbol_short!("music"),
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 7000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3000,
+            },
+        ],
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &Some(symbol_short!("music")));
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        700_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        300_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&default_shareholder, &token_address),
+        0
+    );
+}
+
+#[test]
+fn test_tag_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &None, &Some(symbol_short!("music"))),
+        Err(Ok(Error::TagNotFound))
+    );
+}
+
+#[test]
+fn test_token_share_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token_1, token_address_1) = create_token(&env, &token_admin);
+    let (_, sudo_token_2, token_address_2) = create_token(&env, &token_admin);
+
+    splitter.update_token_shares(
+        &token_address_2,
+        &Some(vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 9000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1000,
+            },
+        ]),
+    );
+
+    sudo_token_1.mint(&splitter_address, &1_000_000_000);
+    sudo_token_2.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address_1, &None, &None);
+    splitter.distribute_tokens(&token_address_2, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address_1),
+        500_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address_1),
+        500_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address_2),
+        900_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address_2),
+        100_000_000
+    );
+}
+
+#[test]
+fn test_scheduled_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    // The first shareholder leaves at the scheduled ledger
+    let shareholder_3 = Address::generate(&env);
+    let new_shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_2.clone(),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: shareholder_3.clone(),
+            share: 5000,
+        },
+    ];
+    splitter.schedule_shares(&(env.ledger().sequence() + 1000), &new_shares);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1000);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        500_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        1_000_000_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address),
+        500_000_000
+    );
+    assert_eq!(splitter.list_shares(&None), new_shares);
+    assert_eq!(splitter.get_share(&shareholder_1, &None), None);
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
     let (splitter, _) = create_splitter(&env);
 
     assert_eq!(
-        splitter.try_distribute_tokens(&Address::generate(&env)),
+        splitter.try_distribute_tokens(&Address::generate(&env), &None, &None),
         Err(Ok(Error::NotInitialized))
     );
 }
@@ -64,5 +1495,7 @@ fn test_unauthorized() {
     let token_admin = Address::generate(&env);
     let (_, _, token_address) = create_token(&env, &token_admin);
 
-    assert!(splitter.try_distribute_tokens(&token_address).is_err());
+    assert!(splitter
+        .try_distribute_tokens(&token_address, &None, &None)
+        .is_err());
 }
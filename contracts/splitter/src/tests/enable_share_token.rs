@@ -0,0 +1,96 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(splitter.try_name(), Err(Ok(Error::ShareTokenNotEnabled)));
+
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    assert_eq!(splitter.name(), String::from_str(&env, "Revenue Share"));
+    assert_eq!(splitter.symbol(), String::from_str(&env, "RVS"));
+    assert_eq!(splitter.decimals(), 2);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_enable_share_token(
+            &String::from_str(&env, "Revenue Share"),
+            &String::from_str(&env, "RVS"),
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_enable_share_token(
+            &String::from_str(&env, "Revenue Share"),
+            &String::from_str(&env, "RVS"),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_enable_share_token(
+            &String::from_str(&env, "Revenue Share"),
+            &String::from_str(&env, "RVS"),
+        ),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_share_token_already_enabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    assert_eq!(
+        splitter.try_enable_share_token(
+            &String::from_str(&env, "Other Share"),
+            &String::from_str(&env, "OTS"),
+        ),
+        Err(Ok(Error::ShareTokenAlreadyEnabled))
+    );
+}
@@ -0,0 +1,282 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal,
+};
+
+use crate::{
+    errors::Error,
+    storage::{FreezeDataKey, ReclaimMode, MAX_FREEZE_DURATION},
+    tests::helpers::{
+        create_splitter, create_splitter_with_arbiter, create_splitter_with_default_shares,
+        create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    assert_eq!(splitter.get_arbiter(), Some(arbiter));
+    assert_eq!(splitter.get_freeze(&shareholder), None);
+
+    splitter.freeze_allocation(&shareholder, &100);
+
+    let freeze = FreezeDataKey {
+        frozen_at: 0,
+        frozen_until: 100,
+    };
+    assert_eq!(splitter.get_freeze(&shareholder), Some(freeze.clone()));
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("freeze"), shareholder.clone()).into_val(&env),
+                freeze.into_val(&env)
+            )
+        ]
+    );
+
+    // Distributions keep accruing during the freeze
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 805);
+
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &805, &None),
+        Err(Ok(Error::AllocationFrozen))
+    );
+
+    splitter.unfreeze_allocation(&shareholder);
+    assert_eq!(splitter.get_freeze(&shareholder), None);
+
+    splitter.withdraw_allocation(&token_address, &shareholder, &805, &None);
+    assert_eq!(token.balance(&shareholder), 805);
+}
+
+#[test]
+fn test_freeze_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    splitter.freeze_allocation(&shareholder, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number = 99);
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &5, &None),
+        Err(Ok(Error::AllocationFrozen))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(splitter.get_freeze(&shareholder), None);
+    splitter.withdraw_allocation(&token_address, &shareholder, &5, &None);
+}
+
+#[test]
+fn test_max_freeze_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+    let shareholder = Address::generate(&env);
+
+    splitter.freeze_allocation(&shareholder, &MAX_FREEZE_DURATION);
+    assert_eq!(
+        splitter.get_freeze(&shareholder),
+        Some(FreezeDataKey {
+            frozen_at: 0,
+            frozen_until: MAX_FREEZE_DURATION
+        })
+    );
+
+    // Extending an active freeze cannot go past the maximum duration from its start
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    splitter.freeze_allocation(&shareholder, &MAX_FREEZE_DURATION);
+    assert_eq!(
+        splitter.get_freeze(&shareholder),
+        Some(FreezeDataKey {
+            frozen_at: 0,
+            frozen_until: MAX_FREEZE_DURATION
+        })
+    );
+
+    // Shortening an active freeze keeps its start
+    splitter.freeze_allocation(&shareholder, &10);
+    assert_eq!(
+        splitter.get_freeze(&shareholder),
+        Some(FreezeDataKey {
+            frozen_at: 0,
+            frozen_until: 1_010
+        })
+    );
+}
+
+#[test]
+fn test_refreeze() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Keep the entries alive across the long ledger jumps
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 3 * MAX_FREEZE_DURATION;
+        li.max_entry_ttl = 3 * MAX_FREEZE_DURATION;
+    });
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+    let shareholder = Address::generate(&env);
+
+    splitter.freeze_allocation(&shareholder, &MAX_FREEZE_DURATION);
+
+    // Unfreezing and freezing again keeps the start of the dispute
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    splitter.unfreeze_allocation(&shareholder);
+    splitter.freeze_allocation(&shareholder, &MAX_FREEZE_DURATION);
+    assert_eq!(
+        splitter.get_freeze(&shareholder),
+        Some(FreezeDataKey {
+            frozen_at: 0,
+            frozen_until: MAX_FREEZE_DURATION
+        })
+    );
+
+    // Freezing again after the expiry keeps the start of the dispute as well
+    env.ledger()
+        .with_mut(|li| li.sequence_number = MAX_FREEZE_DURATION);
+    assert_eq!(splitter.get_freeze(&shareholder), None);
+    assert_eq!(
+        splitter.try_freeze_allocation(&shareholder, &100),
+        Err(Ok(Error::InvalidFreezeDuration))
+    );
+
+    // A new dispute can start once the last freeze has been over for the maximum duration
+    env.ledger()
+        .with_mut(|li| li.sequence_number = 2 * MAX_FREEZE_DURATION);
+    splitter.freeze_allocation(&shareholder, &100);
+    assert_eq!(
+        splitter.get_freeze(&shareholder),
+        Some(FreezeDataKey {
+            frozen_at: 2 * MAX_FREEZE_DURATION,
+            frozen_until: 2 * MAX_FREEZE_DURATION + 100
+        })
+    );
+}
+
+#[test]
+fn test_reclaim_frozen_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    splitter.freeze_allocation(&shareholder, &200);
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(
+        splitter.try_reclaim_allocation(&shareholder, &token_address),
+        Err(Ok(Error::AllocationFrozen))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_freeze_allocation(&Address::generate(&env), &100),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_unfreeze_allocation(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_get_freeze(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+
+    assert!(splitter
+        .try_freeze_allocation(&Address::generate(&env), &100)
+        .is_err());
+}
+
+#[test]
+fn test_no_arbiter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    assert_eq!(splitter.get_arbiter(), None);
+
+    assert_eq!(
+        splitter.try_freeze_allocation(&Address::generate(&env), &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        splitter.try_unfreeze_allocation(&Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_invalid_freeze_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_arbiter(&env, &admin, &arbiter);
+
+    assert_eq!(
+        splitter.try_freeze_allocation(&Address::generate(&env), &0),
+        Err(Ok(Error::InvalidFreezeDuration))
+    );
+    assert_eq!(
+        splitter.try_freeze_allocation(&Address::generate(&env), &(MAX_FREEZE_DURATION + 1)),
+        Err(Ok(Error::InvalidFreezeDuration))
+    );
+}
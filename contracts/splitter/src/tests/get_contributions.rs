@@ -0,0 +1,105 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::{
+    errors::Error,
+    storage::{ContributionDataKey, DepositDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    let payer_1 = Address::generate(&env);
+    let payer_2 = Address::generate(&env);
+    sudo_token.mint(&payer_1, &1_000_000_000);
+    sudo_token.mint(&payer_2, &1_000_000_000);
+
+    assert_eq!(
+        splitter.get_contributions(&payer_1, &token_address),
+        ContributionDataKey {
+            total: 0,
+            deposits: 0
+        }
+    );
+    assert_eq!(splitter.list_payers(&0, &10), vec![&env]);
+
+    let memo = String::from_str(&env, "INV-001");
+    splitter.deposit(
+        &payer_1,
+        &token_address,
+        &300_000_000,
+        &Some(memo.clone()),
+        &None,
+    );
+    splitter.deposit(&payer_2, &token_address, &500_000_000, &None, &None);
+    splitter.deposit(&payer_1, &token_address, &200_000_000, &None, &None);
+
+    assert_eq!(
+        splitter.get_contributions(&payer_1, &token_address),
+        ContributionDataKey {
+            total: 500_000_000,
+            deposits: 2
+        }
+    );
+    assert_eq!(
+        splitter.get_contributions(&payer_2, &token_address),
+        ContributionDataKey {
+            total: 500_000_000,
+            deposits: 1
+        }
+    );
+    assert_eq!(
+        splitter.list_deposits(&payer_1, &token_address, &0, &10),
+        vec![
+            &env,
+            DepositDataKey {
+                amount: 300_000_000,
+                ledger: env.ledger().sequence(),
+            },
+            DepositDataKey {
+                amount: 200_000_000,
+                ledger: env.ledger().sequence(),
+            },
+        ]
+    );
+    assert_eq!(
+        splitter.get_deposit_memo(&payer_1, &token_address, &0),
+        Some(memo)
+    );
+    assert_eq!(
+        splitter.get_deposit_memo(&payer_1, &token_address, &1),
+        None
+    );
+
+    // Payers are listed once in the order of their first deposit
+    assert_eq!(
+        splitter.list_payers(&0, &10),
+        vec![&env, payer_1.clone(), payer_2.clone()]
+    );
+    assert_eq!(splitter.list_payers(&1, &10), vec![&env, payer_2]);
+    assert_eq!(splitter.list_payers(&0, &1), vec![&env, payer_1]);
+    assert_eq!(splitter.list_payers(&2, &10), vec![&env]);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_get_contributions(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_list_payers(&0, &10),
+        Err(Ok(Error::NotInitialized))
+    );
+}
@@ -0,0 +1,165 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::{ShareDataKey, ShareTable, ShareVersionDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let initial_shares = splitter.list_shares(&None);
+    let initial_ledger = env.ledger().sequence();
+
+    assert_eq!(splitter.current_share_version(), 1);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+
+    let new_shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+    ];
+    splitter.update_shares(&new_shares);
+
+    // Scheduled share tables take effect at their activation ledger
+    let scheduled_ledger = env.ledger().sequence() + 100;
+    splitter.schedule_shares(&scheduled_ledger, &initial_shares);
+    env.ledger().with_mut(|li| li.sequence_number += 200);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(splitter.current_share_version(), 3);
+    assert_eq!(
+        splitter.get_shares_at_version(&1),
+        ShareVersionDataKey {
+            id: 1,
+            ledger: initial_ledger,
+            table: ShareTable::Default,
+            shares: initial_shares.clone(),
+        }
+    );
+    assert_eq!(
+        splitter.get_shares_at_version(&2),
+        ShareVersionDataKey {
+            id: 2,
+            ledger: initial_ledger + 100,
+            table: ShareTable::Default,
+            shares: new_shares,
+        }
+    );
+    assert_eq!(
+        splitter.get_shares_at_version(&3),
+        ShareVersionDataKey {
+            id: 3,
+            ledger: scheduled_ledger,
+            table: ShareTable::Default,
+            shares: initial_shares,
+        }
+    );
+}
+
+#[test]
+fn test_tagged_and_token_versions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let ledger = env.ledger().sequence();
+
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+    ];
+
+    let tag = symbol_short!("tag");
+    splitter.update_tagged_shares(&tag, &shares);
+    splitter.remove_tagged_shares(&tag);
+
+    let token_address = Address::generate(&env);
+    splitter.update_token_shares(&token_address, &Some(shares.clone()));
+
+    assert_eq!(splitter.current_share_version(), 4);
+    assert_eq!(
+        splitter.get_shares_at_version(&2),
+        ShareVersionDataKey {
+            id: 2,
+            ledger,
+            table: ShareTable::Tag(tag.clone()),
+            shares: shares.clone(),
+        }
+    );
+    // Removed share tables are kept as an empty version
+    assert_eq!(
+        splitter.get_shares_at_version(&3),
+        ShareVersionDataKey {
+            id: 3,
+            ledger,
+            table: ShareTable::Tag(tag),
+            shares: vec![&env],
+        }
+    );
+    assert_eq!(
+        splitter.get_shares_at_version(&4),
+        ShareVersionDataKey {
+            id: 4,
+            ledger,
+            table: ShareTable::Token(token_address),
+            shares,
+        }
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_get_shares_at_version(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_current_share_version(),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_share_version_not_found() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_get_shares_at_version(&2),
+        Err(Ok(Error::ShareVersionNotFound))
+    );
+}
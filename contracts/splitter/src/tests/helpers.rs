@@ -1,4 +1,11 @@
-use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token, vec,
+    xdr::{AccountId, PublicKey, ScAddress, ToXdr, Uint256},
+    Address, Bytes, BytesN, Env, TryFromVal, Vec,
+};
 use token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
 
 use crate::{
@@ -18,7 +25,7 @@ pub fn create_splitter_with_shares<'a>(
     mutable: &bool,
 ) -> (SplitterClient<'a>, Address) {
     let (client, contract_id) = create_splitter(e);
-    client.init(admin, shares, mutable);
+    client.init(admin, shares, mutable, &None, &0, &None);
     (client, contract_id)
 }
 
@@ -45,6 +52,23 @@ pub fn create_splitter_with_default_shares<'a>(
     (client, contract_id)
 }
 
+pub fn create_splitter_with_arbiter<'a>(
+    e: &'a Env,
+    admin: &Address,
+    arbiter: &Address,
+) -> (SplitterClient<'a>, Address) {
+    let (client, contract_id) = create_splitter(e);
+    client.init(
+        admin,
+        &get_default_share_data(e),
+        &true,
+        &None,
+        &0,
+        &Some(arbiter.clone()),
+    );
+    (client, contract_id)
+}
+
 pub fn create_token<'a>(
     e: &Env,
     admin: &Address,
@@ -70,3 +94,70 @@ pub fn get_default_share_data(env: &Env) -> Vec<ShareDataKey> {
         },
     ]
 }
+
+/// Recipient contract keeping track of the allocations it is notified of
+#[contract]
+pub struct CallbackReceiver;
+
+#[contractimpl]
+impl CallbackReceiver {
+    pub fn on_split_allocated(env: Env, token: Address, amount: i128) {
+        let notified = Self::notified(env.clone(), token.clone());
+        env.storage().instance().set(&token, &(notified + amount));
+    }
+
+    pub fn notified(env: Env, token: Address) -> i128 {
+        env.storage().instance().get(&token).unwrap_or(0)
+    }
+}
+
+pub fn create_callback_receiver(e: &Env) -> (CallbackReceiverClient, Address) {
+    let contract_id = &e.register_contract(None, CallbackReceiver);
+    (
+        CallbackReceiverClient::new(e, contract_id),
+        contract_id.clone(),
+    )
+}
+
+/// Creates a stellar account from the seed of its ed25519 key
+pub fn create_account(e: &Env, seed: u8) -> (SigningKey, BytesN<32>, Address) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32]);
+    let public_key = signing_key.verifying_key().to_bytes();
+    let address = Address::try_from_val(
+        e,
+        &ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+            public_key,
+        )))),
+    )
+    .unwrap();
+    (signing_key, BytesN::from_array(e, &public_key), address)
+}
+
+/// Signs the payload with the ed25519 key
+pub fn sign(e: &Env, signing_key: &SigningKey, payload: &Bytes) -> BytesN<64> {
+    let mut buffer = [0u8; 512];
+    let message = &mut buffer[..payload.len() as usize];
+    payload.copy_into_slice(message);
+    BytesN::from_array(e, &signing_key.sign(message).to_bytes())
+}
+
+/// Returns the leaf of a merkle distribution claim
+pub fn create_merkle_leaf(e: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+    let mut leaf = Bytes::from_array(e, &index.to_be_bytes());
+    leaf.append(&recipient.clone().to_xdr(e));
+    leaf.extend_from_array(&amount.to_be_bytes());
+    let hash: Bytes = e.crypto().sha256(&leaf).into();
+    e.crypto().sha256(&hash)
+}
+
+/// Returns the parent node of two merkle nodes
+pub fn hash_merkle_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a.clone(), b.clone())
+    } else {
+        (b.clone(), a.clone())
+    };
+    let mut pair: Bytes = first.into();
+    pair.append(&second.into());
+    e.crypto().sha256(&pair)
+}
@@ -22,11 +22,11 @@ fn happy_path() {
         },
     ];
 
-    splitter.init(&admin, &shares, &true);
+    splitter.init(&admin, &shares, &true, &None, &0, &None);
 
-    assert_eq!(splitter.get_share(&shareholder_1), Some(8050));
-    assert_eq!(splitter.get_share(&shareholder_2), Some(1950));
-    assert_eq!(splitter.list_shares(), shares);
+    assert_eq!(splitter.get_share(&shareholder_1, &None), Some(8050));
+    assert_eq!(splitter.get_share(&shareholder_2, &None), Some(1950));
+    assert_eq!(splitter.list_shares(&None), shares);
 }
 
 #[test]
@@ -46,10 +46,10 @@ fn test_already_initialized() {
             share: 1950,
         },
     ];
-    splitter.init(&admin, &shares, &true);
+    splitter.init(&admin, &shares, &true, &None, &0, &None);
 
     assert_eq!(
-        splitter.try_init(&admin, &shares, &true),
+        splitter.try_init(&admin, &shares, &true, &None, &0, &None),
         Err(Ok(Error::AlreadyInitialized))
     );
 }
@@ -69,7 +69,7 @@ fn test_low_share_count() {
     ];
 
     assert_eq!(
-        splitter.try_init(&admin, &shares, &true),
+        splitter.try_init(&admin, &shares, &true, &None, &0, &None),
         Err(Ok(Error::LowShareCount))
     );
 }
@@ -95,7 +95,10 @@ fn test_invalid_share_total() {
                     share: 8050,
                 },
             ],
-            &true
+            &true,
+            &None,
+            &0,
+            &None
         ),
         Err(Ok(Error::InvalidShareTotal))
     );
@@ -114,8 +117,46 @@ fn test_invalid_share_total() {
                     share: 50,
                 },
             ],
-            &true
+            &true,
+            &None,
+            &0,
+            &None
         ),
         Err(Ok(Error::InvalidShareTotal))
     );
 }
+
+#[test]
+fn test_invalid_fee() {
+    let env: Env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    let admin = Address::generate(&env);
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 8050,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 1950,
+        },
+    ];
+
+    assert_eq!(
+        splitter.try_init(
+            &admin,
+            &shares,
+            &true,
+            &Some(Address::generate(&env)),
+            &10001,
+            &None
+        ),
+        Err(Ok(Error::InvalidFee))
+    );
+    assert_eq!(
+        splitter.try_init(&admin, &shares, &true, &None, &250, &None),
+        Err(Ok(Error::InvalidFee))
+    );
+}
@@ -1,7 +1,11 @@
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
 
 use crate::{
     errors::Error,
+    storage::ShareDataKey,
     tests::helpers::{create_splitter, create_splitter_with_default_shares},
 };
 
@@ -18,6 +22,39 @@ fn happy_path() {
     assert_eq!(splitter.get_config().mutable, false);
 }
 
+#[test]
+fn test_pending_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let default_shares = splitter.list_shares(&None);
+
+    let ledger = env.ledger().sequence();
+    splitter.schedule_shares(
+        &(ledger + 100),
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+    );
+
+    // Locking cancels the upcoming share tables
+    splitter.lock_contract();
+    assert_eq!(splitter.list_scheduled_shares(), vec![&env]);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(splitter.list_shares(&None), default_shares);
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
@@ -0,0 +1,118 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, BytesN, Env, IntoVal,
+};
+
+use crate::{
+    errors::Error,
+    storage::MerkleRootDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let root = BytesN::from_array(&env, &[1; 32]);
+    splitter.post_merkle_root(&token_address, &root, &600, &100);
+
+    let merkle_root = MerkleRootDataKey {
+        root: root.clone(),
+        total: 600,
+        claimed: 0,
+        deadline: 100,
+        recovered: false,
+    };
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (symbol_short!("merkle"), token_address.clone()).into_val(&env),
+                (0_u32, merkle_root.clone()).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(splitter.get_merkle_epochs(&token_address), 1);
+    assert_eq!(splitter.get_merkle_root(&token_address, &0), merkle_root);
+
+    // The reserved amount is not distributed to the shareholders
+    splitter.distribute_tokens(&token_address, &None, &None);
+    let shares = splitter.list_shares(&None);
+    assert_eq!(
+        splitter.get_allocation(&shares.get(0).unwrap().shareholder, &token_address),
+        322
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_post_merkle_root(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[1; 32]),
+            &600,
+            &100
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_post_merkle_root(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[1; 32]),
+            &600,
+            &100
+        )
+        .is_err());
+}
+
+#[test]
+fn test_invalid_merkle_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let root = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(
+        splitter.try_post_merkle_root(&token_address, &root, &0, &100),
+        Err(Ok(Error::ZeroTransferAmount))
+    );
+    assert_eq!(
+        splitter.try_post_merkle_root(&token_address, &root, &1_001, &100),
+        Err(Ok(Error::TransferAmountAboveUnusedBalance))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_post_merkle_root(&token_address, &root, &600, &99),
+        Err(Ok(Error::DeadlinePassed))
+    );
+}
@@ -0,0 +1,155 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::{Allocation, Distribution, KeeperBountyDataKey, ShareDataKey, ShareTable},
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+    let fee_recipient = Address::generate(&env);
+    splitter.update_fee(&Some(fee_recipient.clone()), &500);
+    splitter.update_keeper_bounty(&Some(KeeperBountyDataKey {
+        fee: 100,
+        cap: None,
+        min_interval: 0,
+    }));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    assert_eq!(
+        splitter.preview_distribution(&token_address, &None, &None),
+        Distribution {
+            token: token_address.clone(),
+            amount: 1_000_000_000,
+            fee: 50_000_000,
+            keeper_bounty: 0,
+            fixed_amounts: vec![&env],
+            tiers: vec![&env],
+            allocations: vec![
+                &env,
+                Allocation {
+                    shareholder: shareholder_1.clone(),
+                    amount: 764_750_000,
+                },
+                Allocation {
+                    shareholder: shareholder_2.clone(),
+                    amount: 185_250_000,
+                },
+            ],
+            recoupments: vec![&env],
+            share_table: ShareTable::Default,
+            share_version: 1,
+        }
+    );
+
+    let preview =
+        splitter.preview_distribution(&token_address, &Some(Address::generate(&env)), &None);
+    assert_eq!(preview.fee, 50_000_000);
+    assert_eq!(preview.keeper_bounty, 9_500_000);
+
+    // Preview matches the executed distribution
+    splitter.distribute_tokens(&token_address, &None, &None);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        764_750_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        185_250_000
+    );
+
+    // Nothing is left to distribute
+    let preview = splitter.preview_distribution(&token_address, &None, &None);
+    assert_eq!(preview.amount, 0);
+    assert_eq!(preview.allocations.len(), 0);
+}
+
+#[test]
+fn test_share_table() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 5000,
+        },
+    ];
+
+    // A due share table is promoted before the preview, so its version can be looked up
+    splitter.schedule_shares(&(env.ledger().sequence() + 100), &shares);
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    let distribution = splitter.preview_distribution(&token_address, &None, &None);
+    assert_eq!(distribution.share_table, ShareTable::Default);
+    assert_eq!(distribution.share_version, 2);
+    assert_eq!(splitter.current_share_version(), 2);
+
+    let tag = symbol_short!("tag");
+    splitter.update_tagged_shares(&tag, &shares);
+    let distribution = splitter.preview_distribution(&token_address, &None, &Some(tag.clone()));
+    assert_eq!(distribution.share_table, ShareTable::Tag(tag));
+    assert_eq!(distribution.share_version, 3);
+
+    splitter.update_token_shares(&token_address, &Some(shares));
+    let distribution = splitter.preview_distribution(&token_address, &None, &None);
+    assert_eq!(distribution.share_table, ShareTable::Token(token_address));
+    assert_eq!(distribution.share_version, 4);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_preview_distribution(&Address::generate(&env), &None, &None),
+        Err(Ok(Error::NotInitialized))
+    );
+}
@@ -0,0 +1,308 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::{ReclaimMode, ShareDataKey},
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(101)
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_reclaim_allocation(&shareholder, &token_address),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    splitter.reclaim_allocation(&shareholder, &token_address);
+
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        None
+    );
+
+    // The reclaimed allocation is back in the unused balance
+    splitter.transfer_tokens(&token_address, &admin, &805);
+}
+
+#[test]
+fn test_withdrawal_restarts_claim_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number = 50);
+    splitter.withdraw_allocation(&token_address, &shareholder, &5, &None);
+
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(151)
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(
+        splitter.try_reclaim_allocation(&shareholder, &token_address),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+}
+
+#[test]
+fn test_credit_keeps_claim_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // The window keeps running from the oldest credit that is not withdrawn
+    env.ledger().with_mut(|li| li.sequence_number = 50);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(101)
+    );
+
+    // Credits after a full withdrawal start a new window
+    let allocation = splitter.get_allocation(&shareholder, &token_address);
+    splitter.withdraw_allocation(&token_address, &shareholder, &allocation, &None);
+    env.ledger().with_mut(|li| li.sequence_number = 80);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(181)
+    );
+}
+
+#[test]
+fn test_streamed_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+    splitter.update_stream_duration(&1_000);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Only the released part is reclaimed, the locked part keeps streaming
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    splitter.reclaim_allocation(&shareholder, &token_address);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        644_000
+    );
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(301)
+    );
+
+    assert_eq!(
+        splitter.try_reclaim_allocation(&shareholder, &token_address),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+}
+
+#[test]
+fn test_claim_window_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    splitter.update_claim_window(&u32::MAX, &ReclaimMode::UnusedBalance);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_reclaim_ledger(&shareholder, &token_address),
+        Some(u32::MAX)
+    );
+    assert_eq!(
+        splitter.try_reclaim_allocation(&shareholder, &token_address),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+}
+
+#[test]
+fn test_redistribute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3750,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 1250,
+            },
+        ],
+        &true,
+    );
+
+    splitter.update_claim_window(&100, &ReclaimMode::Redistribute);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    splitter.reclaim_allocation(&shareholder_1, &token_address);
+
+    // The allocation is distributed again, the part of the reclaimed shareholder is left unused
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 562);
+    assert_eq!(splitter.get_allocation(&shareholder_3, &token_address), 187);
+    splitter.transfer_tokens(&token_address, &admin, &251);
+}
+
+#[test]
+fn test_redistribute_payout_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 2500,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 2500,
+            },
+        ],
+        &true,
+    );
+
+    splitter.update_claim_window(&100, &ReclaimMode::Redistribute);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_payout_cap(&shareholder_2, &token_address, &Some(300));
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    splitter.reclaim_allocation(&shareholder_1, &token_address);
+
+    // The payout caps are respected when the allocation is distributed again
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 300);
+    assert_eq!(splitter.get_allocation(&shareholder_3, &token_address), 400);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_reclaim_allocation(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_zero_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.update_claim_window(&100, &ReclaimMode::UnusedBalance);
+
+    assert_eq!(
+        splitter.try_reclaim_allocation(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::ZeroWithdrawalAmount))
+    );
+}
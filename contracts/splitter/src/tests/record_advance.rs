@@ -0,0 +1,143 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::RecoupmentDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+    let recipient = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+
+    assert_eq!(splitter.get_recoupment(&shareholder, &token_address), None);
+
+    splitter.record_advance(&shareholder, &token_address, &1_000_000_000, &recipient);
+    assert_eq!(
+        splitter.get_recoupment(&shareholder, &token_address),
+        Some(RecoupmentDataKey {
+            recipient: recipient.clone(),
+            balance: 1_000_000_000
+        })
+    );
+
+    // Advances to the same recipient accumulate
+    splitter.record_advance(&shareholder, &token_address, &500_000_000, &recipient);
+    assert_eq!(
+        splitter.get_recoupment(&shareholder, &token_address),
+        Some(RecoupmentDataKey {
+            recipient,
+            balance: 1_500_000_000
+        })
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_record_advance(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &Address::generate(&env)
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_record_advance(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &Address::generate(&env)
+        )
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_record_advance(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000_000,
+            &Address::generate(&env)
+        ),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_advance_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_record_advance(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &Address::generate(&env)
+        ),
+        Err(Ok(Error::InvalidAdvanceAmount))
+    );
+}
+
+#[test]
+fn test_recoupment_recipient_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+    let token_address = Address::generate(&env);
+
+    splitter.record_advance(
+        &shareholder,
+        &token_address,
+        &1_000_000_000,
+        &Address::generate(&env),
+    );
+
+    assert_eq!(
+        splitter.try_record_advance(
+            &shareholder,
+            &token_address,
+            &1_000_000_000,
+            &Address::generate(&env)
+        ),
+        Err(Ok(Error::RecoupmentRecipientMismatch))
+    );
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_merkle_leaf, create_splitter, create_splitter_with_default_shares, create_token,
+        hash_merkle_pair,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipient = Address::generate(&env);
+    let leaves = [
+        create_merkle_leaf(&env, 0, &recipient, 400),
+        create_merkle_leaf(&env, 1, &Address::generate(&env), 600),
+    ];
+    let root = hash_merkle_pair(&env, &leaves[0], &leaves[1]);
+
+    splitter.post_merkle_root(&token_address, &root, &1_000, &100);
+    splitter.claim_merkle(
+        &token_address,
+        &0,
+        &0,
+        &recipient,
+        &400,
+        &vec![&env, leaves[1].clone()],
+    );
+
+    assert_eq!(
+        splitter.try_recover_merkle_funds(&token_address, &0),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    splitter.recover_merkle_funds(&token_address, &0);
+    assert!(splitter.get_merkle_root(&token_address, &0).recovered);
+
+    // The unclaimed amount is back in the unused balance
+    splitter.transfer_tokens(&token_address, &admin, &600);
+
+    assert_eq!(
+        splitter.try_recover_merkle_funds(&token_address, &0),
+        Err(Ok(Error::MerkleAlreadyRecovered))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_recover_merkle_funds(&Address::generate(&env), &0),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_merkle_root_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_recover_merkle_funds(&Address::generate(&env), &0),
+        Err(Ok(Error::MerkleRootNotFound))
+    );
+}
@@ -0,0 +1,347 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{ScheduledSharesDataKey, ShareDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 3333,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3333,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 3334,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &10_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Revenue that is not distributed yet is settled before leaving
+    sudo_token.mint(&splitter_address, &10_000);
+    splitter.update_settlement_tokens(&shareholder_1, &vec![&env, token_address.clone()]);
+
+    splitter.renounce_share(&shareholder_1);
+
+    // The basis point lost to rounding goes to the largest remainder
+    assert_eq!(
+        splitter.list_shares(&None),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 4999,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 5001,
+            },
+        ]
+    );
+    assert_eq!(splitter.current_share_version(), 2);
+
+    // Existing allocations stay with the shareholder
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        6_666
+    );
+}
+
+#[test]
+fn test_locked_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 2000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+    splitter.update_shares_transferable(&true);
+    splitter.lock_contract();
+
+    splitter.renounce_share(&shareholder_1);
+
+    assert_eq!(
+        splitter.list_shares(&None),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 7500,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 2500,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 2000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_renounce_share(&shareholder),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_other_share_tables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    let table = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_1.clone(),
+            share: 2000,
+        },
+        ShareDataKey {
+            shareholder: shareholder_2.clone(),
+            share: 6000,
+        },
+        ShareDataKey {
+            shareholder: shareholder_3.clone(),
+            share: 2000,
+        },
+    ];
+    let renounced = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_2.clone(),
+            share: 7500,
+        },
+        ShareDataKey {
+            shareholder: shareholder_3.clone(),
+            share: 2500,
+        },
+    ];
+
+    let tag = symbol_short!("royalty");
+    splitter.update_tagged_shares(&tag, &table);
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+    splitter.update_token_shares(&token_address, &Some(table.clone()));
+    let activation_ledger = env.ledger().sequence() + 100;
+    splitter.schedule_shares(&activation_ledger, &table);
+
+    // A shareholder missing from the default share table can leave the other share tables
+    splitter.renounce_share(&shareholder_1);
+
+    assert_eq!(splitter.list_tagged_shares(&tag), renounced);
+    assert_eq!(
+        splitter.list_shares(&Some(token_address.clone())),
+        renounced
+    );
+    assert_eq!(
+        splitter.list_scheduled_shares(),
+        vec![
+            &env,
+            ScheduledSharesDataKey {
+                activation_ledger,
+                shares: renounced.clone(),
+            },
+        ]
+    );
+    assert_eq!(
+        splitter.try_renounce_share(&shareholder_1),
+        Err(Ok(Error::ShareholderNotFound))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_renounce_share(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_shareholder_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_renounce_share(&Address::generate(&env)),
+        Err(Ok(Error::ShareholderNotFound))
+    );
+}
+
+#[test]
+fn test_low_share_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_renounce_share(&shareholder),
+        Err(Ok(Error::LowShareCount))
+    );
+}
+
+#[test]
+fn test_no_remaining_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 10000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 0,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 0,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_renounce_share(&shareholder),
+        Err(Ok(Error::LowShareCount))
+    );
+}
@@ -0,0 +1,156 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Vec,
+};
+
+use crate::{
+    errors::Error,
+    storage::{ScheduledSharesDataKey, ShareDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+fn create_shares(env: &Env) -> Vec<ShareDataKey> {
+    vec![
+        env,
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 5000,
+        },
+    ]
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let default_shares = splitter.list_shares(&None);
+
+    let ledger = env.ledger().sequence();
+    let shares_1 = create_shares(&env);
+    let shares_2 = create_shares(&env);
+    let shares_3 = create_shares(&env);
+
+    // Share tables are listed in the order of their activation
+    splitter.schedule_shares(&(ledger + 200), &shares_2);
+    splitter.schedule_shares(&(ledger + 100), &shares_1);
+    assert_eq!(
+        splitter.list_scheduled_shares(),
+        vec![
+            &env,
+            ScheduledSharesDataKey {
+                activation_ledger: ledger + 100,
+                shares: shares_1.clone(),
+            },
+            ScheduledSharesDataKey {
+                activation_ledger: ledger + 200,
+                shares: shares_2.clone(),
+            },
+        ]
+    );
+
+    // Scheduling at the same ledger replaces the share table
+    splitter.schedule_shares(&(ledger + 200), &shares_3);
+    assert_eq!(
+        splitter.list_scheduled_shares().get(1).unwrap().shares,
+        shares_3
+    );
+    assert_eq!(splitter.list_shares(&None), default_shares);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+
+    assert_eq!(splitter.list_shares(&None), shares_1);
+    assert_eq!(
+        splitter.list_scheduled_shares(),
+        vec![
+            &env,
+            ScheduledSharesDataKey {
+                activation_ledger: ledger + 200,
+                shares: shares_3.clone(),
+            },
+        ]
+    );
+
+    splitter.cancel_scheduled_shares(&(ledger + 200));
+    assert_eq!(splitter.list_scheduled_shares(), vec![&env]);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(splitter.list_shares(&None), shares_1);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_schedule_shares(&100, &create_shares(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_cancel_scheduled_shares(&100),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_schedule_shares(&100, &create_shares(&env))
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_schedule_shares(&(env.ledger().sequence() + 100), &create_shares(&env)),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_activation_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_schedule_shares(&env.ledger().sequence(), &create_shares(&env)),
+        Err(Ok(Error::InvalidActivationLedger))
+    );
+}
+
+#[test]
+fn test_schedule_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_cancel_scheduled_shares(&(env.ledger().sequence() + 100)),
+        Err(Ok(Error::ScheduleNotFound))
+    );
+}
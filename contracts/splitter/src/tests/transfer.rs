@@ -0,0 +1,244 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, String,
+};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Revenue that is not distributed yet belongs to the current shareholders
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.update_settlement_tokens(&shareholder_1, &vec![&env, token_address.clone()]);
+
+    splitter.transfer(&shareholder_1, &buyer, &5000);
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                splitter_address.clone(),
+                (
+                    symbol_short!("transfer"),
+                    shareholder_1.clone(),
+                    buyer.clone()
+                )
+                    .into_val(&env),
+                5000_i128.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(splitter.balance(&shareholder_1), 0);
+    assert_eq!(splitter.balance(&buyer), 5000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        1_000
+    );
+    assert_eq!(splitter.get_allocation(&buyer, &token_address), 0);
+
+    // Revenue received after the transfer goes to the buyer
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        1_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        1_500
+    );
+    assert_eq!(splitter.get_allocation(&buyer, &token_address), 500);
+}
+
+#[test]
+fn test_transfer_on_locked_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_transfer(&shareholder, &buyer, &2500),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_transferable_shares_on_locked_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+    splitter.update_shares_transferable(&true);
+    splitter.lock_contract();
+
+    splitter.transfer(&shareholder, &buyer, &2500);
+
+    assert_eq!(splitter.balance(&shareholder), 2500);
+    assert_eq!(splitter.balance(&buyer), 2500);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_transfer(&Address::generate(&env), &Address::generate(&env), &1000),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_share_token_not_enabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_transfer(&shareholder, &Address::generate(&env), &1000),
+        Err(Ok(Error::ShareTokenNotEnabled))
+    );
+    assert_eq!(
+        splitter.try_balance(&shareholder),
+        Err(Ok(Error::ShareTokenNotEnabled))
+    );
+}
+
+#[test]
+fn test_insufficient_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    assert_eq!(
+        splitter.try_transfer(&shareholder, &Address::generate(&env), &5001),
+        Err(Ok(Error::InsufficientShare))
+    );
+}
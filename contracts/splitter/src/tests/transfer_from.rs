@@ -0,0 +1,135 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    splitter.approve(&shareholder, &spender, &3000, &100);
+    splitter.transfer_from(&spender, &shareholder, &buyer, &2000);
+
+    assert_eq!(splitter.balance(&shareholder), 3000);
+    assert_eq!(splitter.balance(&buyer), 2000);
+    assert_eq!(splitter.allowance(&shareholder, &spender), 1000);
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+    splitter.lock_contract();
+
+    splitter.approve(&shareholder, &spender, &3000, &100);
+    assert_eq!(
+        splitter.try_transfer_from(&spender, &shareholder, &Address::generate(&env), &2000),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_transfer_from(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1000
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_insufficient_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+    splitter.enable_share_token(
+        &String::from_str(&env, "Revenue Share"),
+        &String::from_str(&env, "RVS"),
+    );
+
+    splitter.approve(&shareholder, &spender, &1000, &100);
+
+    assert_eq!(
+        splitter.try_transfer_from(&spender, &shareholder, &Address::generate(&env), &1001),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+}
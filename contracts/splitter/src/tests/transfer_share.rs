@@ -0,0 +1,533 @@
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::{ScheduledSharesDataKey, ShareDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Part of the share is moved to a new address
+    splitter.transfer_share(&shareholder_1, &new_wallet, &3000);
+    assert_eq!(
+        splitter.list_shares(&None),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 3000,
+            },
+        ]
+    );
+
+    // The whole share is moved to an existing shareholder
+    splitter.transfer_share(&shareholder_1, &shareholder_2, &5050);
+    assert_eq!(
+        splitter.list_shares(&None),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 7000,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 3000,
+            },
+        ]
+    );
+    assert_eq!(splitter.current_share_version(), 3);
+
+    // Existing allocations stay with the sender
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        805_000_000
+    );
+    assert_eq!(splitter.get_allocation(&new_wallet, &token_address), 0);
+}
+
+#[test]
+fn test_settle_received_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    // Tokens that are not listed by either side are not settled
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    splitter.transfer_share(&shareholder, &buyer, &2500);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
+
+    // Tokens received but never distributed are settled when the sender lists them
+    splitter.update_settlement_tokens(&shareholder, &vec![&env, token_address.clone()]);
+    splitter.transfer_share(&shareholder, &buyer, &2500);
+
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        250_000_000
+    );
+    assert_eq!(splitter.get_allocation(&buyer, &token_address), 250_000_000);
+
+    // Tokens listed by the receiver are settled as well
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.update_settlement_tokens(&shareholder, &vec![&env]);
+    splitter.update_settlement_tokens(&buyer, &vec![&env, token_address.clone()]);
+    splitter.transfer_share(&buyer, &shareholder, &5000);
+
+    assert_eq!(splitter.get_allocation(&buyer, &token_address), 750_000_000);
+}
+
+#[test]
+fn test_self_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder.clone(),
+            share: 8050,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 1950,
+        },
+    ];
+    let (splitter, _) = create_splitter_with_shares(&env, &admin, &shares, &true);
+
+    splitter.transfer_share(&shareholder, &shareholder, &3000);
+    assert_eq!(splitter.list_shares(&None), shares);
+    assert_eq!(splitter.current_share_version(), 1);
+}
+
+#[test]
+fn test_tagged_and_token_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+
+    let tag = symbol_short!("royalty");
+    splitter.update_tagged_shares(
+        &tag,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 4000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 6000,
+            },
+        ],
+    );
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+    splitter.update_token_shares(
+        &token_address,
+        &Some(vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 1000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 9000,
+            },
+        ]),
+    );
+
+    // A quarter of the default share moves a quarter of the share in the other tables
+    splitter.transfer_share(&shareholder_1, &new_wallet, &2000);
+    assert_eq!(
+        splitter.list_tagged_shares(&tag),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 3000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 1000,
+            },
+        ]
+    );
+    assert_eq!(
+        splitter.list_shares(&Some(token_address.clone())),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 750,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 9000,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 250,
+            },
+        ]
+    );
+
+    // The whole share leaves the sender in none of the tables
+    splitter.transfer_share(&shareholder_1, &new_wallet, &6000);
+    assert_eq!(
+        splitter.list_tagged_shares(&tag),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 6000,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 4000,
+            },
+        ]
+    );
+    assert_eq!(
+        splitter.list_shares(&Some(token_address.clone())),
+        vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 9000,
+            },
+            ShareDataKey {
+                shareholder: new_wallet.clone(),
+                share: 1000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_scheduled_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    let activation_ledger = env.ledger().sequence() + 100;
+    splitter.schedule_shares(
+        &activation_ledger,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 2000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 8000,
+            },
+        ],
+    );
+
+    splitter.transfer_share(&shareholder_1, &new_wallet, &5000);
+    assert_eq!(
+        splitter.list_scheduled_shares(),
+        vec![
+            &env,
+            ScheduledSharesDataKey {
+                activation_ledger,
+                shares: vec![
+                    &env,
+                    ShareDataKey {
+                        shareholder: shareholder_2.clone(),
+                        share: 8000,
+                    },
+                    ShareDataKey {
+                        shareholder: new_wallet.clone(),
+                        share: 2000,
+                    },
+                ],
+            },
+        ]
+    );
+
+    // The moved share is active once the schedule is reached
+    env.ledger()
+        .with_mut(|li| li.sequence_number = activation_ledger);
+    assert_eq!(splitter.get_share(&new_wallet, &None), Some(2000));
+    assert_eq!(splitter.get_share(&shareholder_1, &None), None);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_transfer_share(&Address::generate(&env), &Address::generate(&env), &1000),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert!(splitter
+        .try_transfer_share(&shareholder, &Address::generate(&env), &1000)
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_transfer_share(&shareholder, &Address::generate(&env), &1000),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_transferable_shares_on_locked_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    splitter.update_shares_transferable(&true);
+    splitter.lock_contract();
+
+    splitter.transfer_share(&shareholder, &new_wallet, &5000);
+
+    assert_eq!(splitter.get_share(&shareholder, &None), None);
+    assert_eq!(splitter.get_share(&new_wallet, &None), Some(5000));
+}
+
+#[test]
+fn test_invalid_share_transfer_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_transfer_share(&shareholder, &Address::generate(&env), &0),
+        Err(Ok(Error::InvalidShareTransferAmount))
+    );
+}
+
+#[test]
+fn test_insufficient_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        &true,
+    );
+
+    assert_eq!(
+        splitter.try_transfer_share(&shareholder, &Address::generate(&env), &5001),
+        Err(Ok(Error::InsufficientShare))
+    );
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    assert!(!splitter.get_callback(&shareholder));
+
+    splitter.update_callback(&shareholder, &true);
+    assert!(splitter.get_callback(&shareholder));
+
+    splitter.update_callback(&shareholder, &false);
+    assert!(!splitter.get_callback(&shareholder));
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_callback(&Address::generate(&env), &true),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_callback(&Address::generate(&env), &true)
+        .is_err());
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    assert!(!splitter.get_cascade(&shareholder));
+
+    splitter.update_cascade(&shareholder, &true);
+    assert!(splitter.get_cascade(&shareholder));
+
+    splitter.update_cascade(&shareholder, &false);
+    assert!(!splitter.get_cascade(&shareholder));
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_cascade(&Address::generate(&env), &true),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_cascade(&Address::generate(&env), &true)
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_cascade(&Address::generate(&env), &true),
+        Err(Ok(Error::ContractLocked))
+    );
+}
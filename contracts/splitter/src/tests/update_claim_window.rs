@@ -0,0 +1,65 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::ReclaimMode,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let config = splitter.get_config();
+    assert_eq!(config.claim_window, 0);
+    assert_eq!(config.reclaim_mode, ReclaimMode::UnusedBalance);
+
+    splitter.update_claim_window(&1000, &ReclaimMode::Redistribute);
+
+    let config = splitter.get_config();
+    assert_eq!(config.claim_window, 1000);
+    assert_eq!(config.reclaim_mode, ReclaimMode::Redistribute);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_claim_window(&1000, &ReclaimMode::UnusedBalance),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_claim_window(&1000, &ReclaimMode::UnusedBalance)
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_claim_window(&1000, &ReclaimMode::UnusedBalance),
+        Err(Ok(Error::ContractLocked))
+    );
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::FeeDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let fee_recipient = Address::generate(&env);
+    splitter.update_fee(&Some(fee_recipient.clone()), &250);
+
+    assert_eq!(
+        splitter.get_fee(),
+        Some(FeeDataKey {
+            recipient: fee_recipient,
+            fee: 250
+        })
+    );
+
+    splitter.update_fee(&None, &0);
+
+    assert_eq!(splitter.get_fee(), None);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_fee(&Some(Address::generate(&env)), &250),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_fee(&Some(Address::generate(&env)), &250)
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_fee(&Some(Address::generate(&env)), &250),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_fee(&Some(Address::generate(&env)), &10001),
+        Err(Ok(Error::InvalidFee))
+    );
+    assert_eq!(
+        splitter.try_update_fee(&Some(Address::generate(&env)), &-1),
+        Err(Ok(Error::InvalidFee))
+    );
+    assert_eq!(
+        splitter.try_update_fee(&None, &250),
+        Err(Ok(Error::InvalidFee))
+    );
+}
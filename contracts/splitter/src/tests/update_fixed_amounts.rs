@@ -0,0 +1,134 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::FixedAmountDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+
+    assert_eq!(splitter.list_fixed_amounts(&token_address), vec![&env]);
+
+    let fixed_amounts = vec![
+        &env,
+        FixedAmountDataKey {
+            recipient: Address::generate(&env),
+            amount: 50_000_000,
+            period: 0,
+        },
+        FixedAmountDataKey {
+            recipient: Address::generate(&env),
+            amount: 100_000_000,
+            period: 1000,
+        },
+    ];
+    splitter.update_fixed_amounts(&token_address, &fixed_amounts);
+    assert_eq!(splitter.list_fixed_amounts(&token_address), fixed_amounts);
+
+    splitter.update_fixed_amounts(&token_address, &vec![&env]);
+    assert_eq!(splitter.list_fixed_amounts(&token_address), vec![&env]);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_fixed_amounts(&Address::generate(&env), &vec![&env]),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_fixed_amounts(&Address::generate(&env), &vec![&env])
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_fixed_amounts(&Address::generate(&env), &vec![&env]),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_fixed_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_fixed_amounts(
+            &Address::generate(&env),
+            &vec![
+                &env,
+                FixedAmountDataKey {
+                    recipient: Address::generate(&env),
+                    amount: 0,
+                    period: 0,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidFixedAmount))
+    );
+}
+
+#[test]
+fn test_duplicate_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    let recipient = Address::generate(&env);
+
+    assert_eq!(
+        splitter.try_update_fixed_amounts(
+            &Address::generate(&env),
+            &vec![
+                &env,
+                FixedAmountDataKey {
+                    recipient: recipient.clone(),
+                    amount: 100,
+                    period: 0,
+                },
+                FixedAmountDataKey {
+                    recipient,
+                    amount: 200,
+                    period: 1000,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidFixedAmount))
+    );
+}
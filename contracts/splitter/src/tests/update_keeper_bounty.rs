@@ -0,0 +1,97 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::KeeperBountyDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+fn get_keeper_bounty() -> KeeperBountyDataKey {
+    KeeperBountyDataKey {
+        fee: 100,
+        cap: Some(5_000_000),
+        min_interval: 100,
+    }
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(splitter.get_keeper_bounty(), None);
+
+    splitter.update_keeper_bounty(&Some(get_keeper_bounty()));
+    assert_eq!(splitter.get_keeper_bounty(), Some(get_keeper_bounty()));
+
+    splitter.update_keeper_bounty(&None);
+    assert_eq!(splitter.get_keeper_bounty(), None);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_keeper_bounty(&Some(get_keeper_bounty())),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_keeper_bounty(&Some(get_keeper_bounty()))
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_keeper_bounty(&Some(get_keeper_bounty())),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_keeper_bounty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_keeper_bounty(&Some(KeeperBountyDataKey {
+            fee: 10001,
+            cap: None,
+            min_interval: 100,
+        })),
+        Err(Ok(Error::InvalidKeeperBounty))
+    );
+    assert_eq!(
+        splitter.try_update_keeper_bounty(&Some(KeeperBountyDataKey {
+            fee: 100,
+            cap: Some(-1),
+            min_interval: 100,
+        })),
+        Err(Ok(Error::InvalidKeeperBounty))
+    );
+}
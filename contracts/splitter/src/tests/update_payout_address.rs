@@ -0,0 +1,59 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_address = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    assert_eq!(splitter.get_payout_address(&shareholder), shareholder);
+
+    splitter.update_payout_address(&shareholder, &Some(payout_address.clone()));
+    assert_eq!(splitter.get_payout_address(&shareholder), payout_address);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    // Withdrawals are sent to the payout address
+    let allocation = splitter.get_allocation(&shareholder, &token_address);
+    splitter.withdraw_allocation(&token_address, &shareholder, &allocation, &None);
+    assert_eq!(token.balance(&payout_address), allocation);
+    assert_eq!(token.balance(&shareholder), 0);
+
+    splitter.update_payout_address(&shareholder, &None);
+    assert_eq!(splitter.get_payout_address(&shareholder), shareholder);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_payout_address(&Address::generate(&env), &None),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_payout_address(&Address::generate(&env), &Some(Address::generate(&env)))
+        .is_err());
+}
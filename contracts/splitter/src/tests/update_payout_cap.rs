@@ -0,0 +1,103 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::PayoutCap,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+
+    assert_eq!(splitter.get_payout_cap(&shareholder, &token_address), None);
+
+    splitter.update_payout_cap(&shareholder, &token_address, &Some(5_000_000_000));
+    assert_eq!(
+        splitter.get_payout_cap(&shareholder, &token_address),
+        Some(PayoutCap {
+            cap: 5_000_000_000,
+            received: 0
+        })
+    );
+
+    splitter.update_payout_cap(&shareholder, &token_address, &None);
+    assert_eq!(splitter.get_payout_cap(&shareholder, &token_address), None);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_payout_cap(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Some(5_000_000_000)
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_payout_cap(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Some(5_000_000_000)
+        )
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_payout_cap(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Some(5_000_000_000)
+        ),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_payout_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_payout_cap(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Some(-1)
+        ),
+        Err(Ok(Error::InvalidPayoutCap))
+    );
+}
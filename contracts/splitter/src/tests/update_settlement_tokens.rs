@@ -0,0 +1,57 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::MAX_SETTLEMENT_TOKENS,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    assert_eq!(splitter.get_settlement_tokens(&shareholder), vec![&env]);
+
+    let tokens = vec![&env, Address::generate(&env), Address::generate(&env)];
+    splitter.update_settlement_tokens(&shareholder, &tokens);
+    assert_eq!(splitter.get_settlement_tokens(&shareholder), tokens);
+
+    splitter.update_settlement_tokens(&shareholder, &vec![&env]);
+    assert_eq!(splitter.get_settlement_tokens(&shareholder), vec![&env]);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_settlement_tokens(&Address::generate(&env), &vec![&env]),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_too_many_settlement_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares(&None).get(0).unwrap().shareholder;
+
+    let mut tokens: Vec<Address> = Vec::new(&env);
+    for _ in 0..=MAX_SETTLEMENT_TOKENS {
+        tokens.push_back(Address::generate(&env));
+    }
+
+    assert_eq!(
+        splitter.try_update_settlement_tokens(&shareholder, &tokens),
+        Err(Ok(Error::TooManySettlementTokens))
+    );
+}
@@ -54,13 +54,13 @@ fn test_happy_path() {
 
     splitter.update_shares(&new_shares);
 
-    assert_eq!(splitter.get_share(&shareholder_1), Some(4260));
-    assert_eq!(splitter.get_share(&shareholder_2), Some(2748));
-    assert_eq!(splitter.get_share(&shareholder_3), Some(2992));
-    assert_eq!(splitter.list_shares(), new_shares);
+    assert_eq!(splitter.get_share(&shareholder_1, &None), Some(4260));
+    assert_eq!(splitter.get_share(&shareholder_2, &None), Some(2748));
+    assert_eq!(splitter.get_share(&shareholder_3, &None), Some(2992));
+    assert_eq!(splitter.list_shares(&None), new_shares);
 
-    assert_eq!(splitter.get_share(&old_shareholder_1), None);
-    assert_eq!(splitter.get_share(&old_shareholder_2), None);
+    assert_eq!(splitter.get_share(&old_shareholder_1, &None), None);
+    assert_eq!(splitter.get_share(&old_shareholder_2, &None), None);
 }
 
 #[test]
@@ -140,3 +140,28 @@ fn test_invalid_share_total() {
         Err(Ok(Error::InvalidShareTotal))
     );
 }
+
+#[test]
+fn test_contract_locked() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_shares(&vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ]),
+        Err(Ok(Error::ContractLocked))
+    );
+}
@@ -0,0 +1,130 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(splitter.list_tags(), vec![&env]);
+
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 7000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 3000,
+        },
+    ];
+    splitter.update_tagged_shares(&symbol_short!("music"), &shares);
+    splitter.update_tagged_shares(&symbol_short!("merch"), &shares);
+
+    assert_eq!(
+        splitter.list_tags(),
+        vec![&env, symbol_short!("music"), symbol_short!("merch")]
+    );
+    assert_eq!(splitter.list_tagged_shares(&symbol_short!("music")), shares);
+
+    splitter.remove_tagged_shares(&symbol_short!("music"));
+
+    assert_eq!(splitter.list_tags(), vec![&env, symbol_short!("merch")]);
+    assert_eq!(
+        splitter.try_list_tagged_shares(&symbol_short!("music")),
+        Err(Ok(Error::TagNotFound))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_tagged_shares(&symbol_short!("music"), &vec![&env]),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_remove_tagged_shares(&symbol_short!("music")),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_tagged_shares(&symbol_short!("music"), &vec![&env])
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_tagged_shares(&symbol_short!("music"), &vec![&env]),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_share_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_tagged_shares(
+            &symbol_short!("music"),
+            &vec![
+                &env,
+                ShareDataKey {
+                    shareholder: Address::generate(&env),
+                    share: 7000,
+                },
+                ShareDataKey {
+                    shareholder: Address::generate(&env),
+                    share: 2000,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidShareTotal))
+    );
+}
+
+#[test]
+fn test_tag_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_remove_tagged_shares(&symbol_short!("music")),
+        Err(Ok(Error::TagNotFound))
+    );
+}
@@ -0,0 +1,158 @@
+use soroban_sdk::{map, testutils::Address as _, vec, Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{ShareDataKey, TierDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares},
+};
+
+fn get_tiers(env: &Env) -> Vec<TierDataKey> {
+    vec![
+        env,
+        TierDataKey {
+            shares: vec![
+                env,
+                ShareDataKey {
+                    shareholder: Address::generate(env),
+                    share: 10000,
+                },
+            ],
+            caps: map![env, (Address::generate(env), 1_000_000_000)],
+        },
+        TierDataKey {
+            shares: vec![
+                env,
+                ShareDataKey {
+                    shareholder: Address::generate(env),
+                    share: 5000,
+                },
+                ShareDataKey {
+                    shareholder: Address::generate(env),
+                    share: 5000,
+                },
+            ],
+            caps: map![env, (Address::generate(env), 500_000_000)],
+        },
+    ]
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(splitter.list_tiers(), vec![&env]);
+
+    let tiers = get_tiers(&env);
+    splitter.update_tiers(&tiers);
+
+    assert_eq!(splitter.list_tiers(), tiers);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_tiers(&get_tiers(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter.try_update_tiers(&get_tiers(&env)).is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_tiers(&get_tiers(&env)),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_tiers(&vec![
+            &env,
+            TierDataKey {
+                shares: vec![&env],
+                caps: map![&env, (Address::generate(&env), 1_000_000_000)],
+            },
+        ]),
+        Err(Ok(Error::LowShareCount))
+    );
+    assert_eq!(
+        splitter.try_update_tiers(&vec![
+            &env,
+            TierDataKey {
+                shares: vec![
+                    &env,
+                    ShareDataKey {
+                        shareholder: Address::generate(&env),
+                        share: 8050,
+                    },
+                ],
+                caps: map![&env, (Address::generate(&env), 1_000_000_000)],
+            },
+        ]),
+        Err(Ok(Error::InvalidShareTotal))
+    );
+    assert_eq!(
+        splitter.try_update_tiers(&vec![
+            &env,
+            TierDataKey {
+                shares: vec![
+                    &env,
+                    ShareDataKey {
+                        shareholder: Address::generate(&env),
+                        share: 10000,
+                    },
+                ],
+                caps: map![&env, (Address::generate(&env), 0)],
+            },
+        ]),
+        Err(Ok(Error::InvalidTierCap))
+    );
+    assert_eq!(
+        splitter.try_update_tiers(&vec![
+            &env,
+            TierDataKey {
+                shares: vec![
+                    &env,
+                    ShareDataKey {
+                        shareholder: Address::generate(&env),
+                        share: 10000,
+                    },
+                ],
+                caps: map![&env],
+            },
+        ]),
+        Err(Ok(Error::InvalidTierCap))
+    );
+}
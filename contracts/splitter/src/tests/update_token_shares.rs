@@ -0,0 +1,121 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::ShareDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+    let default_shares = splitter.list_shares(&None);
+
+    let token_admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &token_admin);
+
+    // Tokens without an override use the default share table
+    assert_eq!(
+        splitter.list_shares(&Some(token_address.clone())),
+        default_shares
+    );
+
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shares = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_1.clone(),
+            share: 6000,
+        },
+        ShareDataKey {
+            shareholder: shareholder_2.clone(),
+            share: 4000,
+        },
+    ];
+    splitter.update_token_shares(&token_address, &Some(shares.clone()));
+
+    assert_eq!(splitter.list_shares(&Some(token_address.clone())), shares);
+    assert_eq!(splitter.list_shares(&None), default_shares);
+    assert_eq!(
+        splitter.get_share(&shareholder_1, &Some(token_address.clone())),
+        Some(6000)
+    );
+    assert_eq!(splitter.get_share(&shareholder_1, &None), None);
+
+    splitter.update_token_shares(&token_address, &None);
+    assert_eq!(
+        splitter.list_shares(&Some(token_address.clone())),
+        default_shares
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_token_shares(&Address::generate(&env), &None),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_update_token_shares(&Address::generate(&env), &None)
+        .is_err());
+}
+
+#[test]
+fn test_contract_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    splitter.lock_contract();
+
+    assert_eq!(
+        splitter.try_update_token_shares(&Address::generate(&env), &None),
+        Err(Ok(Error::ContractLocked))
+    );
+}
+
+#[test]
+fn test_invalid_share_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert_eq!(
+        splitter.try_update_token_shares(
+            &Address::generate(&env),
+            &Some(vec![
+                &env,
+                ShareDataKey {
+                    shareholder: Address::generate(&env),
+                    share: 6000,
+                },
+                ShareDataKey {
+                    shareholder: Address::generate(&env),
+                    share: 5000,
+                },
+            ])
+        ),
+        Err(Ok(Error::InvalidShareTotal))
+    );
+}
@@ -1,4 +1,7 @@
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
 
 use crate::{
     errors::Error,
@@ -23,27 +26,55 @@ fn happy_path() {
     let (token, sudo_token, token_address) = create_token(&env, &token_admin);
 
     sudo_token.mint(&splitter_address, &1_000_000_000);
-    splitter.distribute_tokens(&token_address);
+    splitter.distribute_tokens(&token_address, &None, &None);
 
-    splitter.withdraw_allocation(&token_address, &shareholder, &500_000_000);
+    splitter.withdraw_allocation(&token_address, &shareholder, &500_000_000, &None);
     assert_eq!(
         splitter.get_allocation(&shareholder, &token_address),
         305_000_000
     );
     assert_eq!(token.balance(&shareholder), 500_000_000);
 
-    splitter.withdraw_allocation(&token_address, &shareholder, &305_000_000);
+    splitter.withdraw_allocation(&token_address, &shareholder, &305_000_000, &None);
     assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
     assert_eq!(token.balance(&shareholder), 805_000_000);
 }
 
+#[test]
+fn test_partial_withdrawal_total_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shares = get_default_share_data(&env);
+    let shareholder = shares.get(0).unwrap().shareholder;
+    let (splitter, splitter_address) = create_splitter_with_shares(&env, &admin, &shares, &true);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    splitter.withdraw_allocation(&token_address, &shareholder, &800_000_000, &None);
+
+    // Only the remaining allocations are reserved, new tokens are fully unused
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.transfer_tokens(&token_address, &admin, &1_000_000_000);
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
     let (splitter, _) = create_splitter(&env);
 
     assert_eq!(
-        splitter.try_withdraw_allocation(&Address::generate(&env), &Address::generate(&env), &1),
+        splitter.try_withdraw_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1,
+            &None
+        ),
         Err(Ok(Error::NotInitialized))
     );
 }
@@ -54,7 +85,12 @@ fn test_unauthorized() {
     let (splitter, _) = create_splitter(&env);
 
     assert!(splitter
-        .try_withdraw_allocation(&Address::generate(&env), &Address::generate(&env), &1)
+        .try_withdraw_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1,
+            &None
+        )
         .is_err());
 }
 
@@ -68,7 +104,12 @@ fn test_zero_withdraw_amount() {
         create_splitter_with_shares(&env, &admin, &get_default_share_data(&env), &true);
 
     assert_eq!(
-        splitter.try_withdraw_allocation(&Address::generate(&env), &Address::generate(&env), &0),
+        splitter.try_withdraw_allocation(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &None
+        ),
         Err(Ok(Error::ZeroWithdrawalAmount))
     );
 }
@@ -86,14 +127,59 @@ fn test_withdrawal_amount_above_allocation() {
     let (_, sudo_token, token_address) = create_token(&env, &token_admin);
 
     sudo_token.mint(&splitter_address, &1_000_000_000);
-    splitter.distribute_tokens(&token_address);
+    splitter.distribute_tokens(&token_address, &None, &None);
 
     assert_eq!(
         splitter.try_withdraw_allocation(
             &Address::generate(&env),
             &Address::generate(&env),
-            &1_000_000_000
+            &1_000_000_000,
+            &None
         ),
         Err(Ok(Error::WithdrawalAmountAboveAllocation))
     );
 }
+
+#[test]
+fn test_withdrawal_amount_above_released_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &share_data, &true);
+    splitter.update_stream_duration(&1_000);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &None, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 500);
+
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &402_500_001, &None),
+        Err(Ok(Error::WithdrawalAmountAboveReleasedAllocation))
+    );
+
+    splitter.withdraw_allocation(&token_address, &shareholder, &402_500_000, &None);
+    assert_eq!(token.balance(&shareholder), 402_500_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        402_500_000
+    );
+    assert_eq!(
+        splitter.get_released_allocation(&shareholder, &token_address),
+        0
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 500);
+
+    splitter.withdraw_allocation(&token_address, &shareholder, &402_500_000, &None);
+    assert_eq!(token.balance(&shareholder), 805_000_000);
+    assert_eq!(splitter.get_allocation(&shareholder, &token_address), 0);
+}
@@ -13,7 +13,8 @@ soroban contract invoke \
   init \
   --admin $(cat scripts/test_identity) \
   --shares '[{"shareholder":"'${SOROSPLIT_SHAREHOLDER1_IDENTITY}'", "share": "8050"},{"shareholder":"'${SOROSPLIT_SHAREHOLDER2_IDENTITY}'", "share": "1950"}]' \
-  --mutable true
+  --mutable true \
+  --fee 0
 
 echo "3. Initializing and minting 100 tokens to splitter contract \n"
 soroban contract invoke \